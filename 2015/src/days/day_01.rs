@@ -0,0 +1,48 @@
+use aoc_lib::solution::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: usize = 2015;
+    const DAY: usize = 1;
+    const TITLE: &'static str = "Not Quite Lisp";
+
+    type Parsed<'a> = &'a str;
+    type Part1 = i64;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_1(input: &Self::Parsed<'_>) -> i64 {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Parsed<'_>) -> usize {
+        part_2(input)
+    }
+}
+
+fn part_1(input: &str) -> i64 {
+    let up = input.chars().filter(|x| *x == '(').count() as i64;
+    let down = input.chars().filter(|x| *x == ')').count() as i64;
+
+    up - down
+}
+
+fn part_2(input: &str) -> usize {
+    let mut floor = 0_i64;
+    for (pos, c) in input.chars().enumerate() {
+        match c {
+            '(' => floor += 1,
+            ')' => floor -= 1,
+            _ => panic!("invalid character, pos {pos}"),
+        }
+        if floor == -1 {
+            // problem is 1-indexed, enumerate() is 0-indexed
+            return pos + 1;
+        }
+    }
+    unreachable!("Never reached basement")
+}
@@ -0,0 +1,65 @@
+use aoc_lib::solution::Solution;
+use itertools::Itertools;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: usize = 2015;
+    const DAY: usize = 2;
+    const TITLE: &'static str = "I Was Told There Would Be No Math";
+
+    type Parsed<'a> = &'a str;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_1(input: &Self::Parsed<'_>) -> u64 {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Parsed<'_>) -> u64 {
+        part_2(input)
+    }
+}
+
+fn parse_input(line: &str) -> [u64; 3] {
+    line.split('x')
+        .map(|x| x.parse::<u64>().unwrap())
+        .collect_vec()
+        .try_into()
+        .unwrap()
+}
+
+fn part_1(input: &str) -> u64 {
+    let mut total = 0_u64;
+
+    for line in input.lines() {
+        let areas = parse_input(line)
+            .iter()
+            .tuple_combinations()
+            .map(|(a, b)| a * b)
+            .collect_vec();
+
+        total += areas.iter().min().unwrap();
+        total += areas.iter().sum::<u64>() * 2;
+    }
+
+    total
+}
+
+fn part_2(input: &str) -> u64 {
+    let mut total = 0_u64;
+
+    for line in input.lines() {
+        let mut lengths = parse_input(line);
+        lengths.sort();
+
+        total += &lengths[0..2].iter().sum::<u64>() * 2;
+        total += lengths.iter().product::<u64>();
+    }
+
+    total
+}
@@ -0,0 +1,55 @@
+use aoc_lib::solution::Solution;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: usize = 2015;
+    const DAY: usize = 4;
+    const TITLE: &'static str = "The Ideal Stocking Stuffer";
+
+    type Parsed<'a> = &'a str;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input.trim()
+    }
+
+    fn part_1(input: &Self::Parsed<'_>) -> u64 {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Parsed<'_>) -> u64 {
+        part_2(input)
+    }
+}
+
+fn get_md5_suffix(input: &str, suffix: u64) -> md5::Digest {
+    md5::compute(format!("{input}{suffix}"))
+}
+
+fn part_1(input: &str) -> u64 {
+    let mut out = 0;
+    for i in 0.. {
+        let md5 = get_md5_suffix(input, i);
+        if md5[0..2] == [0u8; 2] && md5[2] < 0x10 {
+            println!("md5({input}{i}) = {md5:x}");
+            out = i;
+            break;
+        }
+    }
+    out
+}
+
+fn part_2(input: &str) -> u64 {
+    let mut out = 0;
+    for i in 0.. {
+        let md5 = get_md5_suffix(input, i);
+        if md5[0..3] == [0u8; 3] {
+            println!("md5({input}{i}) = {md5:x}");
+            out = i;
+            break;
+        }
+    }
+    out
+}
@@ -0,0 +1,77 @@
+use aoc_lib::solution::Solution;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: usize = 2015;
+    const DAY: usize = 8;
+    const TITLE: &'static str = "Matchsticks";
+
+    type Parsed<'a> = &'a str;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_1(input: &Self::Parsed<'_>) -> u32 {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Parsed<'_>) -> u32 {
+        part_2(input)
+    }
+}
+
+fn part_1(input: &str) -> u32 {
+    input
+        .lines()
+        .map(lengths)
+        .map(|(mem_len, acc_len)| mem_len - acc_len)
+        .sum()
+}
+
+fn part_2(input: &str) -> u32 {
+    input
+        .lines()
+        .map(|s| re_escape_len(s) - s.len() as u32)
+        .sum()
+}
+
+// returns (length_in_memory, length_after escapes)
+fn lengths(line: &str) -> (u32, u32) {
+    let mem_len = line.len() as u32;
+
+    let mut chars = line[1..line.len() - 1].chars();
+    let mut acc_len = 0_u32;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next().unwrap() {
+                'x' => {
+                    chars.next();
+                    chars.next();
+                    acc_len += 1;
+                }
+                '\\' | '"' => {
+                    acc_len += 1;
+                }
+                _ => panic!("Invalid escape"),
+            },
+            _ => acc_len += 1,
+        };
+    }
+
+    (mem_len, acc_len)
+}
+
+fn re_escape_len(line: &str) -> u32 {
+    line.chars()
+        .map(|c| match c {
+            '"' | '\\' => 2,
+            _ => 1,
+        })
+        .sum::<u32>()
+        + 2
+}
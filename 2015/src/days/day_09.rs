@@ -0,0 +1,86 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+use aoc_lib::solution::Solution;
+use itertools::Itertools;
+
+pub struct Connection<'a>(&'a str, &'a str);
+
+impl Hash for Connection<'_> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        (self.0.min(self.1)).hash(state);
+        (self.0.max(self.1)).hash(state);
+    }
+}
+
+impl PartialEq for Connection<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.min(self.1) == other.0.min(other.1) && self.0.max(self.1) == other.0.max(other.1)
+    }
+}
+
+impl Eq for Connection<'_> {}
+
+impl<'a> From<(&'a str, &'a str)> for Connection<'a> {
+    fn from((a, b): (&'a str, &'a str)) -> Self {
+        Self(a, b)
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: usize = 2015;
+    const DAY: usize = 9;
+    const TITLE: &'static str = "All in a Single Night";
+
+    type Parsed<'a> = (HashSet<&'a str>, HashMap<Connection<'a>, u32>);
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_1((set, map): &Self::Parsed<'_>) -> u32 {
+        calculate_routes(set, map).0
+    }
+
+    fn part_2((set, map): &Self::Parsed<'_>) -> u32 {
+        calculate_routes(set, map).1
+    }
+}
+
+fn calculate_routes(set: &HashSet<&str>, map: &HashMap<Connection, u32>) -> (u32, u32) {
+    let mut minimum = u32::MAX;
+    let mut maximum = u32::MIN;
+    for path in set.iter().permutations(set.len()) {
+        let cost = path
+            .iter()
+            .tuple_windows()
+            .map(|(a, b)| map.get(&(**a, **b).into()).unwrap())
+            .sum();
+        minimum = minimum.min(cost);
+        maximum = maximum.max(cost);
+    }
+    (minimum, maximum)
+}
+
+fn parse_input(input: &str) -> (HashSet<&str>, HashMap<Connection<'_>, u32>) {
+    let mut map = HashMap::new();
+    let mut set = HashSet::new();
+    input.lines().map(parse_line).for_each(|(a, b, dist)| {
+        set.insert(a);
+        set.insert(b);
+        map.insert((a, b).into(), dist);
+    });
+    (set, map)
+}
+
+fn parse_line(line: &str) -> (&str, &str, u32) {
+    let (dests, distance) = line.split(" = ").collect_tuple().unwrap();
+    let (from, to) = dests.split(" to ").collect_tuple().unwrap();
+    (from, to, distance.parse().unwrap())
+}
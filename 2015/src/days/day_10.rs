@@ -0,0 +1,49 @@
+use aoc_lib::solution::Solution;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: usize = 2015;
+    const DAY: usize = 10;
+    const TITLE: &'static str = "Elves Look, Elves Say";
+
+    type Parsed<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input.trim()
+    }
+
+    fn part_1(input: &Self::Parsed<'_>) -> usize {
+        (0..40).fold(input.to_string(), |x, _| transform(x)).len()
+    }
+
+    fn part_2(input: &Self::Parsed<'_>) -> usize {
+        (0..50).fold(input.to_string(), |x, _| transform(x)).len()
+    }
+}
+
+fn transform(input: String) -> String {
+    let mut chars = input.chars();
+
+    let mut prev_char = chars.next().unwrap();
+    let mut compressed_char_counts = vec![(prev_char, 1)];
+
+    for c in chars {
+        if prev_char == c {
+            compressed_char_counts.last_mut().unwrap().1 += 1;
+            continue;
+        }
+        compressed_char_counts.push((c, 1));
+        prev_char = c;
+    }
+
+    let mut out = String::with_capacity(compressed_char_counts.len() * 2);
+    for (c, amount) in compressed_char_counts {
+        out.push_str(&amount.to_string());
+        out.push(c);
+    }
+
+    out
+}
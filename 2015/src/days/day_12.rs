@@ -0,0 +1,66 @@
+use aoc_lib::solution::Solution;
+
+use regex::Regex;
+use serde_json::Value;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: usize = 2015;
+    const DAY: usize = 12;
+    const TITLE: &'static str = "JSAbacusFramework.io";
+
+    type Parsed<'a> = &'a str;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_1(input: &Self::Parsed<'_>) -> i32 {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Parsed<'_>) -> i32 {
+        part_2(input)
+    }
+}
+
+fn part_1(input: &str) -> i32 {
+    let re = Regex::new(r#"(\-?[0-9]+)"#).unwrap();
+
+    re.captures_iter(input)
+        .map(|x| x.extract::<1>())
+        .map(|x| x.0)
+        .map(|x| x.parse::<i32>().unwrap())
+        .sum::<i32>()
+}
+
+fn part_2(input: &str) -> i32 {
+    recursive_find_num(&serde_json::from_str(input).unwrap())
+}
+
+fn recursive_find_num(x: &Value) -> i32 {
+    let mut total = 0;
+    match x {
+        Value::Array(vals) => {
+            for val in vals {
+                total += recursive_find_num(val);
+            }
+        }
+        Value::Number(num) => {
+            total += num.as_i64().unwrap() as i32;
+        }
+        Value::Object(map) => {
+            for (_k, v) in map.iter() {
+                if *v == Value::String("red".to_string()) {
+                    return 0;
+                }
+                total += recursive_find_num(v);
+            }
+        }
+        _ => (),
+    };
+    total
+}
@@ -0,0 +1,78 @@
+use aoc_lib::automaton::{life, Bounded};
+use aoc_lib::grid::DenseGrid;
+use aoc_lib::solution::Solution;
+use aoc_lib::vector::{Neighbourhood, Vector};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: usize = 2015;
+    const DAY: usize = 18;
+    const TITLE: &'static str = "Like a GIF For Your Yard";
+
+    type Parsed<'a> = DenseGrid<bool>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_1(grid: &Self::Parsed<'_>) -> usize {
+        part_1(grid)
+    }
+
+    fn part_2(grid: &Self::Parsed<'_>) -> usize {
+        part_2(grid)
+    }
+}
+
+fn part_1(grid: &DenseGrid<bool>) -> usize {
+    lights_on_after(grid, 100, false)
+}
+
+fn part_2(grid: &DenseGrid<bool>) -> usize {
+    lights_on_after(grid, 100, true)
+}
+
+fn lights_on_after(grid: &DenseGrid<bool>, steps: usize, corners_stuck: bool) -> usize {
+    let mut world = Bounded::new(grid.clone(), Neighbourhood::Moore);
+    if corners_stuck {
+        let (right, bottom) = (grid.width() as i32 - 1, grid.height() as i32 - 1);
+        let corners = [[0, 0], [right, 0], [0, bottom], [right, bottom]];
+        world = world.with_stuck(corners.map(|corner| (Vector::new(corner), true)));
+    }
+    world.run(steps, tick);
+    count_on(world.grid())
+}
+
+fn tick(on: &bool, neighbours: &[&bool]) -> bool {
+    let neighbours = neighbours.iter().map(|x| **x).collect::<Vec<_>>();
+    life(&[3], &[2, 3])(*on, &neighbours)
+}
+
+fn count_on(grid: &DenseGrid<bool>) -> usize {
+    grid.values().filter(|x| **x).count()
+}
+
+fn parse_input(input: &str) -> DenseGrid<bool> {
+    DenseGrid::parse(input, |c| match c {
+        '#' => true,
+        '.' => false,
+        _ => panic!("Invalid character: {c}"),
+    })
+}
+
+#[test]
+fn test_example() {
+    let grid = parse_input(
+        ".#.#.#\n\
+         ...##.\n\
+         #....#\n\
+         ..#...\n\
+         #.#..#\n\
+         ####..",
+    );
+    assert_eq!(lights_on_after(&grid, 4, false), 4);
+    assert_eq!(lights_on_after(&grid, 5, true), 17);
+}
@@ -0,0 +1,73 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use aoc_lib::paragraphs::Paragraphs;
+use aoc_lib::solution::Solution;
+use itertools::Itertools;
+use pathfinding::prelude::dijkstra;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: usize = 2015;
+    const DAY: usize = 19;
+    const TITLE: &'static str = "Medicine for Rudolph";
+
+    type Parsed<'a> = (Vec<(&'a str, &'a str)>, &'a str);
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_1((replacements, molecule): &Self::Parsed<'_>) -> u64 {
+        part_1(replacements, molecule)
+    }
+
+    fn part_2((replacements, molecule): &Self::Parsed<'_>) -> u64 {
+        part_2(replacements, molecule)
+    }
+}
+
+fn part_1(replacements: &[(&str, &str)], molecule: &str) -> u64 {
+    let mut set: HashSet<String> = HashSet::new();
+
+    for (from, to) in replacements {
+        for (idx, _match) in molecule.match_indices(from) {
+            let mut s: String = String::from_str(&molecule[0..idx]).unwrap();
+            s.push_str(&molecule[idx..].replacen(from, to, 1));
+            set.insert(s);
+        }
+    }
+
+    set.len() as u64
+}
+
+fn part_2(replacements: &[(&str, &str)], molecule: &str) -> u64 {
+    unimplemented!("This is just a CPU destroyer");
+    // let results = dijkstra(
+    //     &molecule.to_string(),
+    //     |x: &String| replacements.iter().flat_map(|(from, to)| {
+    //         x.match_indices(to).filter_map(move |(idx, _match)| {
+    //             let mut s: String = String::from_str(&x[0..idx]).unwrap();
+    //             s.push_str(&x[idx..].replacen(to, from, 1));
+    //             let l = s.len();
+    //             Some((s, l))
+    //         }).collect_vec()
+    //     }).collect_vec(),
+    //     |x| *x == "e"
+    // );
+    // (results.unwrap().0.len() - 1) as u64
+}
+
+fn parse_input(input: &str) -> (Vec<(&str, &str)>, &str) {
+    let (replacements, mut molecule) = input.paragraphs().collect_tuple().unwrap();
+    let replacements = replacements
+        .into_iter()
+        .map(|s| s.split(" => ").collect_tuple().unwrap())
+        .collect_vec();
+    let molecule = molecule.last().unwrap();
+
+    (replacements, molecule)
+}
@@ -0,0 +1,60 @@
+use aoc_lib::solution::Solution;
+use rayon::prelude::*;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: usize = 2015;
+    const DAY: usize = 20;
+    const TITLE: &'static str = "Infinite Elves and Infinite Houses";
+
+    type Parsed<'a> = u64;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input.trim().parse().unwrap()
+    }
+
+    fn part_1(min_presents: &Self::Parsed<'_>) -> u64 {
+        part_1(*min_presents)
+    }
+
+    fn part_2(min_presents: &Self::Parsed<'_>) -> u64 {
+        part_2(*min_presents)
+    }
+}
+
+fn part_1(min_presents: u64) -> u64 {
+    (1..min_presents)
+        .into_par_iter()
+        .find_map_first(|house| {
+            let presents = (1..=(((house as f64).sqrt() + 1.0) as u64))
+                .filter(|n| house % n == 0)
+                .map(|n| n + (house / n))
+                .sum::<u64>()
+                * 10;
+            if presents < min_presents {
+                return None;
+            }
+            Some(house)
+        })
+        .unwrap()
+}
+
+fn part_2(min_presents: u64) -> u64 {
+    (1..min_presents)
+        .into_par_iter()
+        .find_map_first(|house| {
+            let presents = (1..=(((house as f64).sqrt() + 1.0) as u64))
+                .filter(|n| house % n == 0)
+                .map(|n| if n <= 50 { house / n } else { 0 } + if house / n <= 50 { n } else { 0 })
+                .sum::<u64>()
+                * 11;
+            if presents < min_presents {
+                return None;
+            }
+            Some(house)
+        })
+        .unwrap()
+}
@@ -0,0 +1,78 @@
+use aoc_lib::solution::Solution;
+use itertools::Itertools;
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const YEAR: usize = 2015;
+    const DAY: usize = 24;
+    const TITLE: &'static str = "It Hangs in the Balance";
+
+    type Parsed<'a> = Vec<u128>;
+    type Part1 = u128;
+    type Part2 = u128;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        let mut packages = input
+            .lines()
+            .map(|x| x.parse::<u128>().unwrap())
+            .collect_vec();
+        packages.sort();
+        packages.into_iter().rev().collect_vec()
+    }
+
+    fn part_1(packages: &Self::Parsed<'_>) -> u128 {
+        part_1(packages)
+    }
+
+    fn part_2(packages: &Self::Parsed<'_>) -> u128 {
+        part_2(packages)
+    }
+}
+
+fn part_1(packages: &[u128]) -> u128 {
+    let target = packages.iter().sum::<u128>() / 3;
+    let mut minimum_quantum_entanglement = u128::MAX;
+    find_smallest_fit_recursive(packages, 0, 0, 1, target, &mut minimum_quantum_entanglement);
+    minimum_quantum_entanglement
+}
+
+fn part_2(packages: &[u128]) -> u128 {
+    let target = packages.iter().sum::<u128>() / 4;
+    let mut minimum_quantum_entanglement = u128::MAX;
+    find_smallest_fit_recursive(packages, 0, 0, 1, target, &mut minimum_quantum_entanglement);
+    minimum_quantum_entanglement
+}
+
+fn find_smallest_fit_recursive(
+    packages: &[u128],
+    idx: usize,
+    sum: u128,
+    quantum_entanglement: u128,
+    target: u128,
+    minimum_quantum_entanglement: &mut u128,
+) {
+    if sum == target {
+        *minimum_quantum_entanglement = (*minimum_quantum_entanglement).min(quantum_entanglement);
+        return;
+    }
+    if sum < target && quantum_entanglement < *minimum_quantum_entanglement && idx < packages.len()
+    {
+        find_smallest_fit_recursive(
+            packages,
+            idx + 1,
+            sum,
+            quantum_entanglement,
+            target,
+            minimum_quantum_entanglement,
+        );
+        find_smallest_fit_recursive(
+            packages,
+            idx + 1,
+            sum + packages[idx],
+            quantum_entanglement * packages[idx],
+            target,
+            minimum_quantum_entanglement,
+        );
+    }
+}
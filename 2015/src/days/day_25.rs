@@ -0,0 +1,61 @@
+use aoc_lib::solution::{NoAnswer, Solution};
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const YEAR: usize = 2015;
+    const DAY: usize = 25;
+    const TITLE: &'static str = "Let It Snow";
+
+    type Parsed<'a> = (u32, u32);
+    type Part1 = u64;
+    type Part2 = NoAnswer;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_1((row, column): &Self::Parsed<'_>) -> u64 {
+        let code_nth = get_code_number_for(*row, *column);
+
+        let mut out = 20151125_u64;
+        for _ in 1..code_nth {
+            out *= 252533;
+            out %= 33554393;
+        }
+        out
+    }
+
+    fn part_2(_parsed: &Self::Parsed<'_>) -> NoAnswer {
+        NoAnswer
+    }
+}
+
+fn get_code_number_for(row_aim: u32, col_aim: u32) -> u32 {
+    let mut out = 1;
+    let mut row = 1_u32;
+    let mut col = 1_u32;
+    while (row, col) != (row_aim, col_aim) {
+        out += 1;
+        if row == 1 {
+            row = col + 1;
+            col = 1;
+            continue;
+        }
+        row -= 1;
+        col += 1;
+    }
+    out
+}
+
+fn parse_input(input: &str) -> (u32, u32) {
+    let start = input.find("row").unwrap();
+    let (row, column) = input[start..]
+        .trim()
+        .trim_end_matches('.')
+        .split_once(", ")
+        .unwrap();
+    let (_, row) = row.split_once(' ').unwrap();
+    let (_, column) = column.split_once(' ').unwrap();
+    (row.parse().unwrap(), column.parse().unwrap())
+}
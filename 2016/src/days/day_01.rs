@@ -0,0 +1,62 @@
+use std::collections::HashSet;
+
+use aoc_lib::direction::{Direction4, Turtle};
+use aoc_lib::solution::Solution;
+use aoc_lib::vector::Vector;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: usize = 2016;
+    const DAY: usize = 1;
+    const TITLE: &'static str = "No Time for a Taxicab";
+
+    type Parsed<'a> = Vec<(char, i32)>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+            .split(", ")
+            .map(|x| {
+                let (direction, distance) = x.split_at(1);
+                let distance = distance.parse::<i32>().unwrap();
+                (direction.chars().next().unwrap(), distance)
+            })
+            .collect()
+    }
+
+    fn part_1(movements: &Self::Parsed<'_>) -> u32 {
+        solve(movements.iter().copied()).0
+    }
+
+    fn part_2(movements: &Self::Parsed<'_>) -> u32 {
+        solve(movements.iter().copied()).1
+    }
+}
+
+fn solve(movements: impl Iterator<Item = (char, i32)>) -> (u32, u32) {
+    let mut turtle = Turtle::new(Vector::ZERO, Direction4::Up);
+    let mut visited = HashSet::new();
+    let mut visited_twice = None;
+
+    for (turn, distance) in movements {
+        match turn {
+            'L' => turtle.turn_left(),
+            'R' => turtle.turn_right(),
+            s => panic!("Invalid direction: {s}"),
+        }
+
+        for point in turtle.walk(distance) {
+            if visited_twice.is_none() && !visited.insert(point) {
+                visited_twice = Some(distance_from_start(point));
+            }
+        }
+    }
+
+    (distance_from_start(turtle.position), visited_twice.unwrap())
+}
+
+fn distance_from_start(point: Vector<2>) -> u32 {
+    point.manhattan_distance(Vector::ZERO)
+}
@@ -0,0 +1,75 @@
+use aoc_lib::direction::Direction4;
+use aoc_lib::grid::DenseGrid;
+use aoc_lib::solution::Solution;
+use itertools::Itertools;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: usize = 2016;
+    const DAY: usize = 2;
+    const TITLE: &'static str = "Bathroom Security";
+
+    type Parsed<'a> = Vec<Vec<Direction4>>;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_1(movements: &Self::Parsed<'_>) -> String {
+        part_1(movements)
+    }
+
+    fn part_2(movements: &Self::Parsed<'_>) -> String {
+        part_2(movements)
+    }
+}
+
+// spaces are where there's no key
+const SQUARE_KEYPAD: &str = "123\n456\n789";
+const DIAMOND_KEYPAD: &str = "  1  \n 234 \n56789\n ABC \n  D  ";
+
+fn part_1(movements: &[Vec<Direction4>]) -> String {
+    enter_code(SQUARE_KEYPAD, movements)
+}
+
+fn part_2(movements: &[Vec<Direction4>]) -> String {
+    enter_code(DIAMOND_KEYPAD, movements)
+}
+
+/// Starts on the 5, and ignores any move that would leave the keypad
+fn enter_code(layout: &str, movements: &[Vec<Direction4>]) -> String {
+    let keypad = DenseGrid::parse(layout, |c| c);
+    let mut cursor = keypad
+        .iter()
+        .find_map(|(point, key)| (*key == '5').then_some(point))
+        .unwrap();
+
+    let mut code = String::new();
+    for movement in movements {
+        for direction in movement {
+            let next = cursor + direction.vector();
+            if keypad.get(next).is_some_and(|key| *key != ' ') {
+                cursor = next;
+            }
+        }
+        code.push(keypad[cursor]);
+    }
+    code
+}
+
+fn parse_input(input: &str) -> Vec<Vec<Direction4>> {
+    input
+        .lines()
+        .map(|line| {
+            line.chars()
+                .map(|c| match c {
+                    'U' | 'R' | 'D' | 'L' => Direction4::try_from(c).unwrap(),
+                    c => panic!("Invalid direction: {c}"),
+                })
+                .collect_vec()
+        })
+        .collect_vec()
+}
@@ -0,0 +1,77 @@
+use aoc_lib::solution::Solution;
+use itertools::Itertools;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: usize = 2016;
+    const DAY: usize = 6;
+    const TITLE: &'static str = "Signals and Noise";
+
+    type Parsed<'a> = Vec<Vec<char>>;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_1(all_chars: &Self::Parsed<'_>) -> String {
+        part_1(all_chars)
+    }
+
+    fn part_2(all_chars: &Self::Parsed<'_>) -> String {
+        part_2(all_chars)
+    }
+}
+
+fn part_1(all_chars: &Vec<Vec<char>>) -> String {
+    let mut message = String::with_capacity(all_chars.len());
+
+    for pos in all_chars {
+        message.push(
+            pos.iter()
+                .counts()
+                .into_iter()
+                .max_by_key(|(_c, count)| *count)
+                .map(|(cha, _count)| *cha)
+                .unwrap(),
+        );
+    }
+
+    message
+}
+
+fn part_2(all_chars: &Vec<Vec<char>>) -> String {
+    let mut message = String::with_capacity(all_chars.len());
+
+    for pos in all_chars {
+        message.push(
+            pos.iter()
+                .counts()
+                .into_iter()
+                .min_by_key(|(_c, count)| *count)
+                .map(|(cha, _count)| *cha)
+                .unwrap(),
+        );
+    }
+
+    message
+}
+
+fn parse_input(input: &str) -> Vec<Vec<char>> {
+    let message_length: usize = input.lines().next().unwrap().len();
+    let mut all_chars = vec![];
+
+    for _ in 0..message_length {
+        all_chars.push(vec![]);
+    }
+
+    for line in input.lines() {
+        for (idx, c) in line.chars().enumerate() {
+            all_chars.get_mut(idx).unwrap().push(c);
+        }
+    }
+
+    all_chars
+}
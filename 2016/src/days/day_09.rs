@@ -0,0 +1,76 @@
+use aoc_lib::solution::Solution;
+use itertools::Itertools;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: usize = 2016;
+    const DAY: usize = 9;
+    const TITLE: &'static str = "Explosives in Cyberspace";
+
+    type Parsed<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input.trim()
+    }
+
+    fn part_1(input: &Self::Parsed<'_>) -> usize {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Parsed<'_>) -> usize {
+        part_2(input)
+    }
+}
+
+fn part_1(line: &str) -> usize {
+    decompressed_line_len(line, false)
+}
+
+fn part_2(line: &str) -> usize {
+    decompressed_line_len(line, true)
+}
+
+fn decompressed_line_len(line: &str, recurse: bool) -> usize {
+    if !line.contains(['(', ')']) {
+        return line.len();
+    }
+    let mut iter = line.chars().enumerate();
+    let mut length = 0;
+
+    loop {
+        let Some((i, x)) = iter.next() else {
+            break;
+        };
+
+        if x == '(' {
+            let comp_instruction = iter
+                .take_while_ref(|(_, c)| *c != ')')
+                .map(|(_, c)| c)
+                .collect::<String>();
+            let (comp_len, comp_amount) = comp_instruction
+                .split('x')
+                .map(|x| x.parse::<usize>().unwrap())
+                .collect_tuple()
+                .unwrap();
+            let _ = iter.next(); // )
+            if recurse {
+                let start = comp_instruction.len() + i + 2;
+                length +=
+                    decompressed_line_len(&line[start..(start + comp_len)], true) * comp_amount;
+            } else {
+                length += comp_len * comp_amount;
+            }
+            for _ in 0..comp_len {
+                iter.next();
+            }
+            continue;
+        }
+
+        length += 1;
+    }
+
+    length
+}
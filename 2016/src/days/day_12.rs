@@ -0,0 +1,39 @@
+use crate::assembunny::{self, AssembunnyMachine};
+use aoc_lib::solution::Solution;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: usize = 2016;
+    const DAY: usize = 12;
+    const TITLE: &'static str = "Leonardo's Monorail";
+
+    type Parsed<'a> = Vec<assembunny::Instruction>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        assembunny::parse_input(input)
+    }
+
+    fn part_1(instructions: &Self::Parsed<'_>) -> u32 {
+        part_1(instructions)
+    }
+
+    fn part_2(instructions: &Self::Parsed<'_>) -> u32 {
+        part_2(instructions)
+    }
+}
+
+fn part_1(instructions: &[assembunny::Instruction]) -> u32 {
+    let machine = AssembunnyMachine::new(instructions.to_owned());
+    let machine = machine.execute();
+    machine.get_register('a'.into())
+}
+
+fn part_2(instructions: &[assembunny::Instruction]) -> u32 {
+    let mut machine = AssembunnyMachine::new(instructions.to_owned());
+    machine.set_register('c'.into(), 1);
+    let machine = machine.execute();
+    machine.get_register('a'.into())
+}
@@ -0,0 +1,70 @@
+use aoc_lib::solution::Solution;
+use pathfinding::directed::{bfs::bfs, dijkstra::dijkstra_reach};
+
+const START: (u64, u64) = (1, 1);
+const END: (u64, u64) = (31, 39);
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: usize = 2016;
+    const DAY: usize = 13;
+    const TITLE: &'static str = "A Maze of Twisty Little Cubicles";
+
+    type Parsed<'a> = u64;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input.trim().parse::<u64>().unwrap()
+    }
+
+    fn part_1(input: &Self::Parsed<'_>) -> usize {
+        part_1(*input)
+    }
+
+    fn part_2(input: &Self::Parsed<'_>) -> usize {
+        part_2(*input)
+    }
+}
+
+fn part_1(input: u64) -> usize {
+    bfs(&START, |x| get_candidates(*x, input), |x| *x == END)
+        .expect("No path found")
+        .len()
+        - 1
+}
+
+fn part_2(input: u64) -> usize {
+    dijkstra_reach(&START, |x, _cost| {
+        get_candidates(*x, input).into_iter().map(|a| (a, 1))
+    })
+    .filter(|x| x.total_cost <= 50)
+    .count()
+}
+
+fn is_open((x, y): (u64, u64), input: u64) -> bool {
+    let mut sum = x * x;
+    sum += 3 * x;
+    sum += 2 * x * y;
+    sum += y;
+    sum += y * y;
+    sum += input;
+
+    sum.count_ones() % 2 == 0
+}
+
+fn get_candidates(from: (u64, u64), input: u64) -> Vec<(u64, u64)> {
+    let mut v = vec![
+        (from.0 + 1, from.1), // right
+        (from.0, from.1 + 1), // down
+    ];
+    if from.0 > 0 {
+        v.push((from.0 - 1, from.1)); // left
+    }
+    if from.1 > 0 {
+        v.push((from.0, from.1 - 1)); // up
+    }
+    v.retain(|x| is_open(*x, input));
+    v
+}
@@ -0,0 +1,96 @@
+use aoc_lib::automaton::Bounded;
+use aoc_lib::grid::DenseGrid;
+use aoc_lib::solution::Solution;
+use aoc_lib::vector::Neighbourhood;
+use itertools::Itertools;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: usize = 2016;
+    const DAY: usize = 18;
+    const TITLE: &'static str = "Like a Rogue";
+
+    type Parsed<'a> = Vec<Tile>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input.trim())
+    }
+
+    fn part_1(first_row: &Self::Parsed<'_>) -> usize {
+        part_1(first_row)
+    }
+
+    fn part_2(first_row: &Self::Parsed<'_>) -> usize {
+        part_2(first_row)
+    }
+}
+
+fn part_1(first_row: &Vec<Tile>) -> usize {
+    count_safe_tiles(first_row, 40)
+}
+
+fn part_2(first_row: &Vec<Tile>) -> usize {
+    count_safe_tiles(first_row, 400_000)
+}
+
+// each row is a step of a world one row high, in which a tile's only neighbours are the ones
+// either side of it
+fn count_safe_tiles(first_row: &[Tile], rows: usize) -> usize {
+    let row = DenseGrid::new(first_row.len(), 1, first_row.to_vec());
+    let mut world = Bounded::new(row, Neighbourhood::VonNeumann);
+    let mut total_safe = count_safe(world.grid());
+
+    for _ in 1..rows {
+        world.step(next_tile);
+        total_safe += count_safe(world.grid());
+    }
+
+    total_safe
+}
+
+fn count_safe(row: &DenseGrid<Tile>) -> usize {
+    row.values().filter(|x| x.is_safe()).count()
+}
+
+fn parse_input(input: &str) -> Vec<Tile> {
+    input.chars().map(Tile::from).collect_vec()
+}
+
+/// The four patterns that make a trap, `^^.`, `.^^`, `^..` and `..^`, all come down to the
+/// tiles to the left and right above being different. Past the walls is safe, so along them
+/// that's the one tile there is being a trap.
+fn next_tile(_above: &Tile, neighbours: &[&Tile]) -> Tile {
+    match neighbours.iter().filter(|x| x.is_trap()).count() {
+        1 => Tile::Trap,
+        _ => Tile::Safe,
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Tile {
+    Safe,
+    Trap,
+}
+
+impl Tile {
+    fn is_safe(&self) -> bool {
+        matches!(self, Self::Safe)
+    }
+
+    fn is_trap(&self) -> bool {
+        matches!(self, Self::Trap)
+    }
+}
+
+impl From<char> for Tile {
+    fn from(value: char) -> Self {
+        match value {
+            '.' => Tile::Safe,
+            '^' => Tile::Trap,
+            _ => panic!("Invalid character for tile: {value}"),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use aoc_lib::solution::Solution;
+use rangemap::RangeInclusiveMap;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: usize = 2016;
+    const DAY: usize = 20;
+    const TITLE: &'static str = "Firewall Rules";
+
+    type Parsed<'a> = Firewall;
+    type Part1 = Ip;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_1(blocked: &Self::Parsed<'_>) -> Ip {
+        part_1(blocked)
+    }
+
+    fn part_2(blocked: &Self::Parsed<'_>) -> u32 {
+        part_2(blocked)
+    }
+}
+
+fn part_1(blocked: &Firewall) -> Ip {
+    *blocked
+        .iter()
+        .filter(|(_range, status)| status.allowed())
+        .map(|(range, _status)| range.start())
+        .next()
+        .unwrap()
+}
+
+fn part_2(blocked: &Firewall) -> u32 {
+    blocked
+        .iter()
+        .filter(|(_range, status)| status.allowed())
+        .map(|(range, _status)| range.end() - range.start() + 1)
+        .sum::<u32>()
+}
+
+fn parse_input(input: &str) -> Firewall {
+    let mut map = RangeInclusiveMap::new();
+    map.insert(0..=u32::MAX, Status::Allowed);
+    input.lines().for_each(|line| {
+        let (lower, upper) = line.split_once('-').unwrap();
+        let (lower, upper) = (lower.parse().unwrap(), upper.parse().unwrap());
+        map.insert(lower..=upper, Status::Blocked);
+    });
+    map
+}
+
+pub type Ip = u32;
+pub type Firewall = RangeInclusiveMap<Ip, Status>;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Status {
+    Allowed,
+    Blocked,
+}
+
+impl Status {
+    fn allowed(&self) -> bool {
+        matches!(self, Self::Allowed)
+    }
+}
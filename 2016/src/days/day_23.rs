@@ -0,0 +1,41 @@
+use crate::assembunny;
+use crate::assembunny::AssembunnyMachine;
+use aoc_lib::solution::Solution;
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const YEAR: usize = 2016;
+    const DAY: usize = 23;
+    const TITLE: &'static str = "Safe Cracking";
+
+    type Parsed<'a> = Vec<assembunny::Instruction>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        assembunny::parse_input(input)
+    }
+
+    fn part_1(instructions: &Self::Parsed<'_>) -> u32 {
+        part_1(instructions)
+    }
+
+    fn part_2(instructions: &Self::Parsed<'_>) -> u32 {
+        part_2(instructions)
+    }
+}
+
+fn part_1(instructions: &[assembunny::Instruction]) -> u32 {
+    let mut machine = AssembunnyMachine::new(instructions.to_owned());
+    machine.set_register('a'.into(), 7);
+    let machine = machine.execute();
+    machine.get_register('a'.into())
+}
+
+fn part_2(instructions: &[assembunny::Instruction]) -> u32 {
+    let mut machine = AssembunnyMachine::new(instructions.to_owned());
+    machine.set_register('a'.into(), 12);
+    let machine = machine.execute();
+    machine.get_register('a'.into())
+}
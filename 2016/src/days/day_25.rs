@@ -0,0 +1,57 @@
+use crate::assembunny::{self, AssembunnyMachine, Instruction, MachineResult};
+use aoc_lib::solution::{NoAnswer, Solution};
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const YEAR: usize = 2016;
+    const DAY: usize = 25;
+    const TITLE: &'static str = "Clock Signal";
+
+    type Parsed<'a> = Vec<Instruction>;
+    type Part1 = u32;
+    type Part2 = NoAnswer;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        assembunny::parse_input(input)
+    }
+
+    fn part_1(instructions: &Self::Parsed<'_>) -> u32 {
+        part_1(instructions)
+    }
+
+    fn part_2(_instructions: &Self::Parsed<'_>) -> NoAnswer {
+        NoAnswer
+    }
+}
+
+fn part_1(instructions: &[Instruction]) -> u32 {
+    for i in 0..10000 {
+        if trial(i, instructions, 10) {
+            return i;
+        }
+    }
+    panic!("Couldn't find solution");
+}
+
+fn trial(initialise_a_to: u32, instructions: &[Instruction], cycles: usize) -> bool {
+    let mut machine = AssembunnyMachine::new(instructions.to_owned());
+    machine.set_register('a'.into(), initialise_a_to);
+    let mut target = vec![0, 1].into_iter().cycle();
+    let mut seen = 0;
+    loop {
+        if seen >= cycles {
+            return true;
+        }
+        if let MachineResult::OutOfInstructions = machine.step() {
+            return false;
+        }
+        let Some(out) = machine.pop_out() else {
+            continue;
+        };
+        seen += 1;
+        if out != target.next().unwrap() {
+            return false;
+        }
+    }
+}
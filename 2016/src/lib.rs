@@ -1,7 +1,12 @@
-use aoc_lib::get_input_year;
+use aoc_lib::{try_get_input_year, AocError};
 
 pub mod assembunny;
+pub mod days;
+
+pub fn try_get_input(day: usize) -> Result<String, AocError> {
+    try_get_input_year(2016, day)
+}
 
 pub fn get_input(day: usize) -> String {
-    get_input_year(2016, day)
+    try_get_input(day).unwrap_or_else(|e| panic!("{e}"))
 }
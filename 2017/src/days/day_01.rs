@@ -0,0 +1,58 @@
+use std::iter;
+
+use aoc_lib::solution::Solution;
+use itertools::Itertools;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: usize = 2017;
+    const DAY: usize = 1;
+    const TITLE: &'static str = "Inverse Captcha";
+
+    type Parsed<'a> = &'a str;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input.trim()
+    }
+
+    fn part_1(input: &Self::Parsed<'_>) -> u32 {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Parsed<'_>) -> u32 {
+        part_2(input)
+    }
+}
+
+fn part_1(input: &str) -> u32 {
+    let mut out: u32 = 0;
+
+    for (a, b) in input
+        .chars()
+        .chain(iter::once(input.chars().next().unwrap()))
+        .tuple_windows()
+    {
+        if a == b {
+            out += a.to_digit(10).unwrap();
+        }
+    }
+    out
+}
+
+fn part_2(input: &str) -> u32 {
+    assert!(input.len() % 2 == 0);
+    let left = input.chars();
+    let right = input.chars().cycle().skip(input.len() / 2);
+
+    let mut out: u32 = 0;
+
+    for (a, b) in left.zip(right) {
+        if a == b {
+            out += a.to_digit(10).unwrap();
+        }
+    }
+    out
+}
@@ -0,0 +1,59 @@
+use aoc_lib::solution::Solution;
+use itertools::Itertools;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: usize = 2017;
+    const DAY: usize = 2;
+    const TITLE: &'static str = "Corruption Checksum";
+
+    type Parsed<'a> = &'a str;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_1(input: &Self::Parsed<'_>) -> u32 {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Parsed<'_>) -> u32 {
+        part_2(input)
+    }
+}
+
+fn part_1(input: &str) -> u32 {
+    input.lines().map(parse_line).map(min_max_diff).sum()
+}
+
+fn part_2(input: &str) -> u32 {
+    input
+        .lines()
+        .map(parse_line)
+        .map(evenly_divisible_pair)
+        .sum()
+}
+
+fn parse_line(line: &str) -> Vec<u32> {
+    line.split_whitespace()
+        .map(|x| x.parse().unwrap())
+        .collect_vec()
+}
+
+fn min_max_diff(row: Vec<u32>) -> u32 {
+    row.iter().max().unwrap() - row.iter().min().unwrap()
+}
+
+fn evenly_divisible_pair(row: Vec<u32>) -> u32 {
+    for (a, b) in row.iter().tuple_combinations() {
+        if a % b == 0 {
+            return a / b;
+        } else if b % a == 0 {
+            return b / a;
+        }
+    }
+    panic!("No solution found");
+}
@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use aoc_lib::direction::{Direction4, Turtle};
+use aoc_lib::solution::Solution;
+use aoc_lib::vector::Vector;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: usize = 2017;
+    const DAY: usize = 3;
+    const TITLE: &'static str = "Spiral Memory";
+
+    type Parsed<'a> = usize;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input.trim().parse::<usize>().unwrap()
+    }
+
+    fn part_1(input: &Self::Parsed<'_>) -> usize {
+        part_1(*input)
+    }
+
+    fn part_2(input: &Self::Parsed<'_>) -> usize {
+        part_2(*input)
+    }
+}
+
+fn part_1(input: usize) -> usize {
+    let point = SpiralIterator::new().nth(input - 1).unwrap();
+    point.manhattan_distance(Vector::ZERO) as usize
+}
+
+fn part_2(input: usize) -> usize {
+    let mut map = HashMap::from([(Vector::ZERO, 1_usize)]);
+    for point in SpiralIterator::new().skip(1) {
+        let value = point
+            .neighbours_diagonals()
+            .map(|neighbour| map.get(&neighbour).copied().unwrap_or(0))
+            .sum();
+        map.insert(point, value);
+
+        if value >= input {
+            return value;
+        }
+    }
+    unreachable!()
+}
+
+/// Spirals out from the origin, starting to the right and turning left at each corner
+#[derive(Debug)]
+struct SpiralIterator {
+    turtle: Turtle,
+    side_length_minus_1: usize,
+    i: usize,
+}
+
+impl SpiralIterator {
+    fn new() -> Self {
+        SpiralIterator {
+            turtle: Turtle::new(Vector::ZERO, Direction4::Right),
+            side_length_minus_1: 0,
+            i: 1,
+        }
+    }
+}
+
+impl Iterator for SpiralIterator {
+    type Item = Vector<2>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let out = self.turtle.position;
+
+        self.turtle.forward(1);
+        self.i -= 1;
+
+        if self.i == 0 {
+            self.i = match self.turtle.heading {
+                // need to push into the next cycle
+                Direction4::Down => self.side_length_minus_1 + 1,
+                Direction4::Right => {
+                    self.side_length_minus_1 += 2;
+                    // we are already 1 above the bottom right corner
+                    self.side_length_minus_1 - 1
+                }
+                _ => self.side_length_minus_1,
+            };
+            self.turtle.turn_left();
+        }
+
+        Some(out)
+    }
+}
@@ -0,0 +1,52 @@
+use aoc_lib::solution::Solution;
+use itertools::Itertools;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: usize = 2017;
+    const DAY: usize = 4;
+    const TITLE: &'static str = "High-Entropy Passphrases";
+
+    type Parsed<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_1(input: &Self::Parsed<'_>) -> usize {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Parsed<'_>) -> usize {
+        part_2(input)
+    }
+}
+
+fn part_1(input: &str) -> usize {
+    input
+        .lines()
+        .filter(|x| !contains_repeated_words(x))
+        .count()
+}
+
+fn part_2(input: &str) -> usize {
+    input.lines().filter(|x| !contains_anagrams(x)).count()
+}
+
+fn contains_repeated_words(line: &str) -> bool {
+    line.split_whitespace()
+        .counts()
+        .iter()
+        .any(|(_, count)| *count > 1)
+}
+
+fn contains_anagrams(line: &str) -> bool {
+    line.split_whitespace()
+        .map(|word| word.chars().sorted().collect_vec())
+        .counts()
+        .iter()
+        .any(|(_, count)| *count > 1)
+}
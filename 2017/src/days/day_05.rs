@@ -0,0 +1,88 @@
+use aoc_lib::solution::Solution;
+use itertools::Itertools;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: usize = 2017;
+    const DAY: usize = 5;
+    const TITLE: &'static str = "A Maze of Twisty Trampolines, All Alike";
+
+    type Parsed<'a> = Vec<Instruction>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_instructions(input)
+    }
+
+    fn part_1(instructions: &Self::Parsed<'_>) -> usize {
+        part_1(instructions)
+    }
+
+    fn part_2(instructions: &Self::Parsed<'_>) -> usize {
+        part_2(instructions)
+    }
+}
+
+fn part_1(instructions: &[Instruction]) -> usize {
+    fn jump_inc_1(cursor: &mut isize, instruction: &mut Instruction) {
+        *cursor += *instruction;
+        *instruction += 1;
+    }
+
+    let mut machine = Machine::new(instructions.to_owned(), jump_inc_1);
+    machine.run()
+}
+
+fn part_2(instructions: &[Instruction]) -> usize {
+    fn jump_dec_1_if_3(cursor: &mut isize, instruction: &mut Instruction) {
+        *cursor += *instruction;
+        if *instruction >= 3 {
+            *instruction -= 1;
+        } else {
+            *instruction += 1;
+        }
+    }
+
+    let mut machine = Machine::new(instructions.to_owned(), jump_dec_1_if_3);
+    machine.run()
+}
+
+pub type Instruction = isize;
+
+struct Machine {
+    cursor: isize,
+    instructions: Vec<Instruction>,
+    step_count: usize,
+    on_each_step: fn(&mut isize, &mut Instruction),
+}
+
+impl Machine {
+    fn new(instructions: Vec<Instruction>, f: fn(&mut isize, &mut Instruction)) -> Self {
+        Machine {
+            instructions,
+            on_each_step: f,
+            step_count: 0,
+            cursor: 0,
+        }
+    }
+
+    fn run(&mut self) -> usize {
+        loop {
+            if self.cursor.is_negative() {
+                break;
+            }
+            let Some(instruction) = self.instructions.get_mut(self.cursor.unsigned_abs()) else {
+                break;
+            };
+            (self.on_each_step)(&mut self.cursor, instruction);
+            self.step_count += 1;
+        }
+        self.step_count
+    }
+}
+
+fn parse_instructions(input: &str) -> Vec<Instruction> {
+    input.lines().filter_map(|x| x.parse().ok()).collect_vec()
+}
@@ -0,0 +1,84 @@
+use aoc_lib::cycle;
+use aoc_lib::solution::Solution;
+use itertools::Itertools;
+
+const BANK_COUNT: usize = 16;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: usize = 2017;
+    const DAY: usize = 6;
+    const TITLE: &'static str = "Memory Reallocation";
+
+    type Parsed<'a> = Allocator;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_1(allocator: &Self::Parsed<'_>) -> usize {
+        solve(allocator).0
+    }
+
+    fn part_2(allocator: &Self::Parsed<'_>) -> usize {
+        solve(allocator).1
+    }
+}
+
+fn solve(allocator: &Allocator) -> (usize, usize) {
+    let cycle = cycle::hashed(allocator.clone(), |allocator| {
+        let mut allocator = allocator.clone();
+        allocator.reallocate(allocator.get_max());
+        allocator
+    });
+    (cycle.start + cycle.length, cycle.length)
+}
+
+fn parse_input(input: &str) -> Allocator {
+    let banks: [Bank; BANK_COUNT] = input
+        .split_whitespace()
+        .map(|x| x.parse().unwrap())
+        .map(|x| Bank { blocks: x })
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap();
+    Allocator { banks }
+}
+
+#[derive(Clone, Hash, PartialEq, Eq)]
+pub struct Allocator {
+    banks: [Bank; BANK_COUNT],
+}
+
+impl Allocator {
+    fn get_max(&self) -> usize {
+        self.banks
+            .iter()
+            .enumerate()
+            .max_set_by_key(|(_num, bank)| bank.blocks)
+            .into_iter()
+            .min_by_key(|(num, _bank)| *num)
+            .unwrap()
+            .0
+    }
+
+    fn reallocate(&mut self, bank: usize) {
+        let mut blocks_to_reallocate = self.banks[bank].blocks;
+        self.banks[bank].blocks = 0;
+
+        let mut bank_num = (bank + 1) % self.banks.len();
+        while blocks_to_reallocate > 0 {
+            self.banks[bank_num].blocks += 1;
+            blocks_to_reallocate -= 1;
+            bank_num = (bank_num + 1) % self.banks.len();
+        }
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+struct Bank {
+    blocks: usize,
+}
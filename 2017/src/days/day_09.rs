@@ -0,0 +1,71 @@
+use aoc_lib::solution::Solution;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: usize = 2017;
+    const DAY: usize = 9;
+    const TITLE: &'static str = "Stream Processing";
+
+    type Parsed<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_1(input: &Self::Parsed<'_>) -> usize {
+        run_to_completion(input).0
+    }
+
+    fn part_2(input: &Self::Parsed<'_>) -> usize {
+        run_to_completion(input).1
+    }
+}
+
+fn run_to_completion(input: &str) -> (usize, usize) {
+    let mut chars = input.trim().chars();
+    let mut state = State {
+        nested_group: 0,
+        in_garbage: false,
+        score: 0,
+        garbage_count: 0,
+    };
+
+    while let Some(c) = chars.next() {
+        match (c, state.in_garbage) {
+            ('!', _) => {
+                // skip next character
+                let _ = chars.next();
+            }
+            ('<', false) => {
+                state.in_garbage = true;
+            }
+            ('>', true) => {
+                state.in_garbage = false;
+            }
+            ('}', false) => {
+                state.nested_group -= 1;
+            }
+            ('{', false) => {
+                state.nested_group += 1;
+                state.score += state.nested_group;
+            }
+            (_, true) => {
+                state.garbage_count += 1;
+            }
+            _ => {}
+        }
+    }
+
+    (state.score, state.garbage_count)
+}
+
+#[derive(Debug)]
+struct State {
+    nested_group: usize,
+    in_garbage: bool,
+    garbage_count: usize,
+    score: usize,
+}
@@ -0,0 +1,42 @@
+use crate::knot_hasher::{bytes_to_hex, hash, twist};
+use aoc_lib::solution::Solution;
+use itertools::Itertools;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: usize = 2017;
+    const DAY: usize = 10;
+    const TITLE: &'static str = "Knot Hash";
+
+    type Parsed<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input.trim()
+    }
+
+    fn part_1(input: &Self::Parsed<'_>) -> usize {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Parsed<'_>) -> String {
+        part_2(input)
+    }
+}
+
+fn part_1(input: &str) -> usize {
+    let instructions = input
+        .trim()
+        .split(',')
+        .map(|x| x.parse().unwrap())
+        .collect_vec();
+
+    let result = twist(&instructions, 1);
+    result[0] as usize * result[1] as usize
+}
+
+fn part_2(plaintext: &str) -> String {
+    bytes_to_hex(&hash(plaintext))
+}
@@ -0,0 +1,42 @@
+use aoc_lib::hex::{self, Orientation};
+use aoc_lib::solution::Solution;
+use aoc_lib::vector::Vector;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: usize = 2017;
+    const DAY: usize = 11;
+    const TITLE: &'static str = "Hex Ed";
+
+    type Parsed<'a> = Vec<Vector<2>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        Orientation::FlatTop.parse_steps(input).unwrap()
+    }
+
+    fn part_1(steps: &Self::Parsed<'_>) -> usize {
+        solve(steps).0
+    }
+
+    fn part_2(steps: &Self::Parsed<'_>) -> usize {
+        solve(steps).1
+    }
+}
+
+fn solve(steps: &[Vector<2>]) -> (usize, usize) {
+    let mut current = Vector::ZERO;
+    let mut max_distance = 0;
+
+    for step in steps {
+        current += *step;
+        max_distance = max_distance.max(hex::distance(Vector::ZERO, current));
+    }
+
+    (
+        hex::distance(Vector::ZERO, current) as usize,
+        max_distance as usize,
+    )
+}
@@ -0,0 +1,65 @@
+use crate::knot_hasher;
+use aoc_lib::{
+    grid::{DenseGrid, Search},
+    solution::Solution,
+    vector::Neighbourhood,
+};
+use itertools::Itertools;
+
+const WIDTH: usize = 128;
+const HEIGHT: usize = 128;
+
+pub type Grid = DenseGrid<bool>;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: usize = 2017;
+    const DAY: usize = 14;
+    const TITLE: &'static str = "Disk Defragmentation";
+
+    type Parsed<'a> = Grid;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        build_grid(input.trim())
+    }
+
+    fn part_1(grid: &Self::Parsed<'_>) -> usize {
+        part_1(grid)
+    }
+
+    fn part_2(grid: &Self::Parsed<'_>) -> usize {
+        part_2(grid)
+    }
+}
+
+fn part_1(grid: &Grid) -> usize {
+    grid.values().filter(|x| **x).count()
+}
+
+fn part_2(grid: &Grid) -> usize {
+    grid.connected_components(Neighbourhood::VonNeumann, |used| *used)
+        .len()
+}
+
+fn build_grid(input: &str) -> Grid {
+    let cells = (0..HEIGHT)
+        .flat_map(|x| {
+            knot_hasher::hash(&format!("{input}-{x}"))
+                .iter()
+                .flat_map(|u| format!("{u:8b}").chars().map(|v| v == '1').collect_vec())
+                .collect_vec()
+        })
+        .collect_vec();
+
+    DenseGrid::new(WIDTH, HEIGHT, cells)
+}
+
+#[test]
+fn test_example() {
+    let grid = build_grid("flqrgnkx");
+    assert_eq!(part_1(&grid), 8108);
+    assert_eq!(part_2(&grid), 1242);
+}
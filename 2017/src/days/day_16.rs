@@ -0,0 +1,98 @@
+use std::collections::VecDeque;
+
+use aoc_lib::cycle;
+use aoc_lib::solution::Solution;
+use itertools::Itertools;
+
+const PART_2_DANCE_TIMES: usize = 1_000_000_000;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: usize = 2017;
+    const DAY: usize = 16;
+    const TITLE: &'static str = "Permutation Promenade";
+
+    type Parsed<'a> = Vec<DanceMove>;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input.trim())
+    }
+
+    fn part_1(moves: &Self::Parsed<'_>) -> String {
+        part_1(moves)
+    }
+
+    fn part_2(moves: &Self::Parsed<'_>) -> String {
+        part_2(moves)
+    }
+}
+
+fn part_1(moves: &[DanceMove]) -> String {
+    let mut dancers = ('a'..='p').collect();
+    dance(&mut dancers, moves);
+    dancers.into_iter().collect()
+}
+
+fn part_2(moves: &[DanceMove]) -> String {
+    let dancers = cycle::nth_state(
+        ('a'..='p').collect::<VecDeque<_>>(),
+        |dancers| {
+            let mut dancers = dancers.clone();
+            dance(&mut dancers, moves);
+            dancers
+        },
+        PART_2_DANCE_TIMES,
+    );
+    dancers.into_iter().collect()
+}
+
+fn dance(dancers: &mut VecDeque<char>, moves: &[DanceMove]) {
+    for dance_move in moves {
+        match dance_move {
+            DanceMove::Spin(x) => dancers.rotate_right(x % dancers.len()),
+            DanceMove::Exchange(a, b) => dancers.swap(*a, *b),
+            DanceMove::Partner(a, b) => {
+                let a_pos = dancers.iter().position(|x| *x == *a).unwrap();
+                let b_pos = dancers.iter().position(|x| *x == *b).unwrap();
+                dancers.swap(a_pos, b_pos);
+            }
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Vec<DanceMove> {
+    input.trim().split(',').map(parse_dance_move).collect_vec()
+}
+
+fn parse_dance_move(input: &str) -> DanceMove {
+    let mut chars = input.chars();
+    let out = match chars.next().unwrap() {
+        's' => DanceMove::Spin(input[1..].parse().unwrap()),
+        'x' => {
+            let (a, b) = input[1..].split_once('/').unwrap();
+            DanceMove::Exchange(a.parse().unwrap(), b.parse().unwrap())
+        }
+        'p' => {
+            let a = chars.next().unwrap();
+            assert!(chars.next().unwrap() == '/');
+            let b = chars.next().unwrap();
+            assert_eq!(
+                chars.next(),
+                None,
+                "Should be no left over characters: {input}"
+            );
+            DanceMove::Partner(a, b)
+        }
+        _ => panic!("Invalid dance move"),
+    };
+    out
+}
+
+pub enum DanceMove {
+    Spin(usize),
+    Exchange(usize, usize),
+    Partner(char, char),
+}
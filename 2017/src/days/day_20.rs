@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+
+use aoc_lib::solution::Solution;
+use aoc_lib::vector::Vector;
+use rayon::prelude::*;
+use regex::{Captures, Regex};
+
+const SIMULATION_ROUNDS: usize = 100_000;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: usize = 2017;
+    const DAY: usize = 20;
+    const TITLE: &'static str = "Particle Swarm";
+
+    type Parsed<'a> = HashMap<usize, Particle>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_1(particles: &Self::Parsed<'_>) -> usize {
+        part_1(particles.clone())
+    }
+
+    fn part_2(particles: &Self::Parsed<'_>) -> usize {
+        part_2(particles.clone())
+    }
+}
+
+fn part_1(mut particles: HashMap<usize, Particle>) -> usize {
+    particles
+        .par_iter_mut()
+        .for_each(|(_p_num, p)| p.tick_n(SIMULATION_ROUNDS));
+
+    particles
+        .into_iter()
+        .min_by_key(|(_particle_num, particle)| particle.manhattan_distance_from_origin())
+        .unwrap()
+        .0
+}
+
+fn part_2(mut particles: HashMap<usize, Particle>) -> usize {
+    for _ in 0..SIMULATION_ROUNDS {
+        let mut positions = HashMap::<Vector<3, i64>, Vec<usize>>::new();
+
+        for (particle_num, particle) in particles.iter_mut() {
+            particle.tick();
+            positions
+                .entry(particle.position)
+                .or_insert(Vec::with_capacity(2))
+                .push(*particle_num);
+        }
+
+        for (_pos, particle_nums) in positions.into_iter().filter(|x| x.1.len() > 1) {
+            particle_nums.into_iter().for_each(|x| {
+                particles.remove(&x);
+            });
+        }
+    }
+    particles.len()
+}
+
+fn parse_input(input: &str) -> HashMap<usize, Particle> {
+    let re = Regex::new(
+        r#"(?x)^
+        p=<(?<x_pos>-?\d+),(?<y_pos>-?\d+),(?<z_pos>-?\d+)>,\s
+        v=<(?<x_vel>-?\d+),(?<y_vel>-?\d+),(?<z_vel>-?\d+)>,\s
+        a=<(?<x_acc>-?\d+),(?<y_acc>-?\d+),(?<z_acc>-?\d+)>
+    $"#,
+    )
+    .unwrap();
+
+    fn extract(capture: &Captures<'_>, name: &str) -> Vector<3, i64> {
+        Vector::new(
+            ["x", "y", "z"].map(|axis| capture[&*format!("{axis}_{name}")].parse().unwrap()),
+        )
+    }
+
+    input
+        .lines()
+        .enumerate()
+        .map(|(line_num, s)| {
+            let c = &re.captures(s).expect("Line did not match regex");
+            (
+                line_num,
+                Particle {
+                    position: extract(c, "pos"),
+                    velocity: extract(c, "vel"),
+                    acceleration: extract(c, "acc"),
+                },
+            )
+        })
+        .collect()
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Particle {
+    position: Vector<3, i64>,
+    velocity: Vector<3, i64>,
+    acceleration: Vector<3, i64>,
+}
+
+impl Particle {
+    fn tick(&mut self) {
+        self.velocity += self.acceleration;
+        self.position += self.velocity;
+    }
+
+    fn tick_n(&mut self, n: usize) {
+        for _ in 0..n {
+            self.velocity += self.acceleration;
+            self.position += self.velocity;
+        }
+    }
+
+    fn manhattan_distance_from_origin(&self) -> usize {
+        self.position.manhattan_distance(Vector::ZERO) as usize
+    }
+}
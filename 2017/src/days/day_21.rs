@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+use aoc_lib::{grid::DenseGrid, solution::Solution};
+use itertools::Itertools;
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const YEAR: usize = 2017;
+    const DAY: usize = 21;
+    const TITLE: &'static str = "Fractal Art";
+
+    type Parsed<'a> = Rules;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_1(rules: &Self::Parsed<'_>) -> usize {
+        run(&starting_grid(), rules, 5)
+    }
+
+    fn part_2(rules: &Self::Parsed<'_>) -> usize {
+        run(&starting_grid(), rules, 18)
+    }
+}
+
+fn starting_grid() -> Grid {
+    parse_grid(".#./..#/###")
+}
+
+fn run(starting_grid: &Grid, rules: &Rules, iterations: usize) -> usize {
+    let mut grid = starting_grid.clone();
+
+    for _ in 0..iterations {
+        let size = if grid.width().is_multiple_of(2) { 2 } else { 3 };
+        let enhanced = grid
+            .tiles(size)
+            .iter()
+            .map(|tile| rules[tile].clone())
+            .collect_vec();
+        grid = DenseGrid::stitch(&enhanced, grid.width() / size).unwrap();
+    }
+
+    grid.values().filter(|x| **x).count()
+}
+
+// Every rotation and flip of a pattern is stored, so enhancing a square is a single lookup
+fn parse_input(input: &str) -> Rules {
+    input
+        .lines()
+        .flat_map(|line| {
+            let (pattern, result) = line.split_once(" => ").unwrap();
+            let result = parse_grid(result);
+            parse_grid(pattern)
+                .symmetries()
+                .map(move |pattern| (pattern, result.clone()))
+        })
+        .collect()
+}
+
+fn parse_grid(grid: &str) -> Grid {
+    DenseGrid::parse(&grid.replace('/', "\n"), |cell| cell == '#')
+}
+
+type Grid = DenseGrid<bool>;
+pub type Rules = HashMap<Grid, Grid>;
+
+#[test]
+fn test_example() {
+    let rules = parse_input("../.# => ##./#../...\n.#./..#/### => #..#/..../..../#..#");
+
+    assert_eq!(run(&starting_grid(), &rules, 2), 12);
+}
@@ -0,0 +1,105 @@
+use std::{collections::HashSet, sync::Mutex};
+
+use aoc_lib::solution::Solution;
+use itertools::Itertools;
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const YEAR: usize = 2017;
+    const DAY: usize = 24;
+    const TITLE: &'static str = "Electromagnetic Moat";
+
+    type Parsed<'a> = HashSet<(usize, usize)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_1(components: &Self::Parsed<'_>) -> usize {
+        build_bridges(components).0
+    }
+
+    fn part_2(components: &Self::Parsed<'_>) -> usize {
+        build_bridges(components).1
+    }
+}
+
+// (strength of the strongest bridge, strength of the longest bridge)
+fn build_bridges(components: &HashSet<(usize, usize)>) -> (usize, usize) {
+    let strongest_bridge = Mutex::new(0);
+    let longest_bridge = Mutex::new((0, 0));
+    build_bridge_recursive(&[], &strongest_bridge, &longest_bridge, components);
+
+    (
+        strongest_bridge.into_inner().unwrap(),
+        longest_bridge.into_inner().unwrap().0,
+    )
+}
+
+fn build_bridge_recursive(
+    path: &[(usize, usize)],
+    strongest_bridge: &Mutex<usize>,
+    longest_bridge: &Mutex<(usize, usize)>,
+    remaining_components: &HashSet<(usize, usize)>,
+) {
+    let exposed_port = path.last().unwrap_or(&(0, 0)).1;
+    let lefts = remaining_components
+        .iter()
+        .filter(|(a, _)| *a == exposed_port)
+        .copied()
+        .collect_vec();
+    let rights = remaining_components
+        .iter()
+        .filter(|(_, b)| *b == exposed_port)
+        .copied()
+        .collect_vec();
+
+    if lefts.is_empty() && rights.is_empty() {
+        let current_strength = calculate_strength(path);
+        let mut inner = strongest_bridge.lock().unwrap();
+        *inner = current_strength.max(*inner);
+
+        let mut inner = longest_bridge.lock().unwrap();
+        if path.len() > inner.1 || (path.len() == inner.1 && current_strength > inner.0) {
+            *inner = (current_strength, path.len());
+        }
+
+        return;
+    }
+
+    lefts.into_par_iter().for_each(|left| {
+        let new_path = path.iter().chain(&[left]).copied().collect_vec();
+        let mut remaining = remaining_components.clone();
+        remaining.remove(&left);
+        build_bridge_recursive(&new_path, strongest_bridge, longest_bridge, &remaining);
+    });
+
+    rights.into_par_iter().for_each(|right| {
+        let new_path = path.iter().chain(&[flip(right)]).copied().collect_vec();
+        let mut remaining = remaining_components.clone();
+        remaining.remove(&right);
+        build_bridge_recursive(&new_path, strongest_bridge, longest_bridge, &remaining);
+    });
+}
+
+fn calculate_strength(path: &[(usize, usize)]) -> usize {
+    path.iter().map(|(a, b)| *a + *b).sum::<usize>()
+}
+
+fn flip(x: (usize, usize)) -> (usize, usize) {
+    (x.1, x.0)
+}
+
+fn parse_input(input: &str) -> HashSet<(usize, usize)> {
+    input
+        .lines()
+        .map(|s| {
+            let (a, b) = s.split_once('/').unwrap();
+            (a.parse().unwrap(), b.parse().unwrap())
+        })
+        .collect()
+}
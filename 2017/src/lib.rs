@@ -1,10 +1,18 @@
+// used in day_25
+#![feature(anonymous_lifetime_in_impl_trait)]
+
+pub mod days;
 pub mod duet_asm;
 mod groups;
 pub mod knot_hasher;
 
-use aoc_lib::get_input_year;
+use aoc_lib::{try_get_input_year, AocError};
 pub use groups::count_groups;
 
+pub fn try_get_input(day: usize) -> Result<String, AocError> {
+    try_get_input_year(2017, day)
+}
+
 pub fn get_input(day: usize) -> String {
-    get_input_year(2017, day)
+    try_get_input(day).unwrap_or_else(|e| panic!("{e}"))
 }
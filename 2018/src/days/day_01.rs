@@ -0,0 +1,52 @@
+use aoc_lib::solution::Solution;
+use itertools::Itertools;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: usize = 2018;
+    const DAY: usize = 1;
+    const TITLE: &'static str = "Chronal Calibration";
+
+    type Parsed<'a> = Vec<isize>;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_1(operations: &Self::Parsed<'_>) -> isize {
+        part_1(operations)
+    }
+
+    fn part_2(operations: &Self::Parsed<'_>) -> isize {
+        part_2(operations)
+    }
+}
+
+fn part_1(operations: &[isize]) -> isize {
+    operations.iter().sum()
+}
+
+fn part_2(operations: &[isize]) -> isize {
+    let mut i = 0;
+    operations
+        .iter()
+        .cycle()
+        .map(|x| {
+            i += *x;
+            i
+        })
+        .duplicates()
+        .next()
+        .unwrap()
+}
+
+fn parse_input(input: &str) -> Vec<isize> {
+    input
+        .lines()
+        .map(|line| line.trim_start_matches('+'))
+        .map(|line| line.parse().unwrap())
+        .collect_vec()
+}
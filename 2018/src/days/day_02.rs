@@ -0,0 +1,62 @@
+use aoc_lib::solution::Solution;
+use itertools::Itertools;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: usize = 2018;
+    const DAY: usize = 2;
+    const TITLE: &'static str = "Inventory Management System";
+
+    type Parsed<'a> = Vec<&'a str>;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input.lines().collect_vec()
+    }
+
+    fn part_1(box_ids: &Self::Parsed<'_>) -> usize {
+        part_1(box_ids)
+    }
+
+    fn part_2(box_ids: &Self::Parsed<'_>) -> String {
+        part_2(box_ids)
+    }
+}
+
+fn part_1(box_ids: &[&str]) -> usize {
+    let counts = box_ids.iter().map(|x| x.chars().counts()).collect_vec();
+
+    counts
+        .iter()
+        .filter(|x| x.values().any(|a| *a == 3))
+        .count()
+        * counts
+            .iter()
+            .filter(|x| x.values().any(|a| *a == 2))
+            .count()
+}
+
+fn part_2(box_ids: &[&str]) -> String {
+    for (a, b) in box_ids.iter().tuple_combinations() {
+        let differences = a
+            .chars()
+            .zip(b.chars())
+            .enumerate()
+            .filter(|(_pos, (a, b))| *a != *b)
+            .collect_vec();
+
+        if differences.len() != 1 {
+            continue;
+        }
+
+        return a
+            .chars()
+            .enumerate()
+            .filter(|(pos, _x)| *pos != differences[0].0)
+            .map(|(_pos, x)| x)
+            .collect();
+    }
+    unreachable!("No solution found");
+}
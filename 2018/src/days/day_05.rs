@@ -0,0 +1,74 @@
+use aho_corasick::AhoCorasick;
+use aoc_lib::solution::Solution;
+use itertools::Itertools;
+use rayon::prelude::*;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: usize = 2018;
+    const DAY: usize = 5;
+    const TITLE: &'static str = "Alchemical Reduction";
+
+    type Parsed<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input.trim()
+    }
+
+    fn part_1(input: &Self::Parsed<'_>) -> usize {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Parsed<'_>) -> usize {
+        part_2(input)
+    }
+}
+
+fn part_1(input: &str) -> usize {
+    replace_all(input).len()
+}
+
+fn part_2(input: &str) -> usize {
+    ('a'..='z')
+        .zip('A'..='Z')
+        .par_bridge()
+        .into_par_iter()
+        .map(|x| input.replace([x.0, x.1], ""))
+        .map(|x| replace_all(&x).len())
+        .min()
+        .unwrap()
+}
+
+fn replace_all(input: &str) -> String {
+    let replacements = ('a'..='z')
+        .zip('A'..='Z')
+        .chain(('A'..='Z').zip('a'..='z'))
+        .map(|x| format!("{}{}", x.0, x.1))
+        .collect_vec();
+    let ac = AhoCorasick::new(replacements).unwrap();
+
+    let mut last = input.trim().to_string();
+    loop {
+        let mut next = String::with_capacity(last.len() / 2);
+        ac.replace_all_with(&last, &mut next, |_, _, _| true);
+        if next.len() == last.len() {
+            break;
+        }
+        last = next;
+    }
+
+    last
+}
+
+#[test]
+fn test_replace() {
+    let inputs = ["aA", "abBA", "abAB", "aabAAB", "dabAcCaCBAcCcaDA"];
+    let outputs = ["", "", "abAB", "aabAAB", "dabCBAcaDA"];
+
+    for (input, output) in inputs.into_iter().zip(outputs) {
+        assert_eq!(replace_all(input), output);
+    }
+}
@@ -0,0 +1,87 @@
+use aoc_lib::solution::Solution;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: usize = 2018;
+    const DAY: usize = 8;
+    const TITLE: &'static str = "Memory Maneuver";
+
+    type Parsed<'a> = Node;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_1(root: &Self::Parsed<'_>) -> usize {
+        part_1(root)
+    }
+
+    fn part_2(root: &Self::Parsed<'_>) -> usize {
+        part_2(root)
+    }
+}
+
+fn part_1(root: &Node) -> usize {
+    root.metadata_sum()
+}
+
+fn part_2(root: &Node) -> usize {
+    root.value()
+}
+
+#[derive(Debug)]
+pub struct Node {
+    children: Vec<Node>,
+    metadata: Vec<usize>,
+}
+
+impl Node {
+    fn metadata_sum(&self) -> usize {
+        self.children
+            .iter()
+            .map(|x| x.metadata_sum())
+            .sum::<usize>()
+            + self.metadata.iter().sum::<usize>()
+    }
+
+    fn value(&self) -> usize {
+        if self.children.is_empty() {
+            return self.metadata.iter().sum::<usize>();
+        }
+        self.metadata
+            .iter()
+            .filter_map(|x| self.children.get(x - 1).map(|x| x.value()))
+            .sum::<usize>()
+    }
+}
+
+fn parse_input(input: &str) -> Node {
+    let mut input = input
+        .split_whitespace()
+        .map(|x| x.parse::<usize>().unwrap());
+    parse_node_recursive(&mut input)
+}
+
+fn parse_node_recursive(input: &mut impl Iterator<Item = usize>) -> Node {
+    let child_count = input.next().expect("Early EOF when reading child_count");
+    let metadata_count = input.next().expect("Early EOF when reading metadata_count");
+
+    let mut node = Node {
+        children: vec![],
+        metadata: vec![],
+    };
+
+    for _ in 0..child_count {
+        node.children.push(parse_node_recursive(input));
+    }
+
+    for _ in 0..metadata_count {
+        node.metadata
+            .push(input.next().expect("Early EOF when reading metadata"));
+    }
+
+    node
+}
@@ -0,0 +1,103 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_lib::automaton::ActiveSet;
+use aoc_lib::cycle;
+use aoc_lib::solution::Solution;
+use aoc_lib::vector::{Neighbourhood, Vector};
+use itertools::Itertools;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: usize = 2018;
+    const DAY: usize = 12;
+    const TITLE: &'static str = "Subterranean Sustainability";
+
+    type Parsed<'a> = (HashSet<Vector<1>>, HashMap<[bool; 5], bool>);
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_1((plants, transformations): &Self::Parsed<'_>) -> i64 {
+        part_1(plants, transformations)
+    }
+
+    fn part_2((plants, transformations): &Self::Parsed<'_>) -> i64 {
+        part_2(plants, transformations)
+    }
+}
+
+fn part_1(plants: &HashSet<Vector<1>>, transformations: &HashMap<[bool; 5], bool>) -> i64 {
+    sum_after(plants, transformations, 20)
+}
+
+fn part_2(plants: &HashSet<Vector<1>>, transformations: &HashMap<[bool; 5], bool>) -> i64 {
+    sum_after(plants, transformations, 50_000_000_000)
+}
+
+fn parse_input(input: &str) -> (HashSet<Vector<1>>, HashMap<[bool; 5], bool>) {
+    let mut lines = input.lines();
+
+    (
+        lines
+            .next()
+            .unwrap()
+            .trim_start_matches("initial state: ")
+            .chars()
+            .enumerate()
+            .filter(|(_i, c)| *c == '#')
+            .map(|(i, _c)| Vector::new([i as i32]))
+            .collect(),
+        lines
+            .skip(1)
+            .map(|x| x.split_once(" => ").unwrap())
+            .map(|(from, to)| {
+                (
+                    from.chars()
+                        .map(|x| x == '#')
+                        .collect_vec()
+                        .try_into()
+                        .unwrap(),
+                    to == "#",
+                )
+            })
+            .collect(),
+    )
+}
+
+// the plants settle into a pattern that keeps moving along, so skip ahead once it repeats. The
+// pots would run past an i32 by then, so how far the pattern has moved is kept to one side.
+fn sum_after(
+    plants: &HashSet<Vector<1>>,
+    transformations: &HashMap<[bool; 5], bool>,
+    generations: usize,
+) -> i64 {
+    let world = ActiveSet::new(plants.iter().copied(), Neighbourhood::Within(2));
+    let (world, offset) = cycle::nth_state_drifting(
+        (world, 0),
+        |(world, offset)| {
+            let mut world = world.clone();
+            world.step(|plant, neighbours| {
+                let [far_left, left, right, far_right] = neighbours.try_into().unwrap();
+                transformations.get(&[far_left, left, plant, right, far_right]) == Some(&true)
+            });
+            (world, *offset)
+        },
+        generations,
+        |(world, offset)| {
+            let pots = world.live().iter().map(|pot| pot[0]).sorted().collect_vec();
+            let first = pots.first().copied().unwrap_or_default();
+            let shape = pots.iter().map(|x| x - first).collect_vec();
+            (shape, i64::from(first) + offset)
+        },
+        |(world, offset), by| (world.clone(), offset + by),
+    );
+    world
+        .live()
+        .iter()
+        .map(|pot| i64::from(pot[0]) + offset)
+        .sum()
+}
@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+
+use aoc_lib::direction::{Direction4, Turtle};
+use aoc_lib::solution::Solution;
+use aoc_lib::vector::Vector;
+use itertools::Itertools;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: usize = 2018;
+    const DAY: usize = 13;
+    const TITLE: &'static str = "Mine Cart Madness";
+
+    type Parsed<'a> = Grid;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        let mut grid = parse_input(input);
+        grid.run_until_final_cart();
+        grid
+    }
+
+    fn part_1(grid: &Self::Parsed<'_>) -> String {
+        part_1(grid)
+    }
+
+    fn part_2(grid: &Self::Parsed<'_>) -> String {
+        part_2(grid)
+    }
+}
+
+fn part_1(grid: &Grid) -> String {
+    let collision = grid.collisions[0];
+    format!("({}, {})", collision.x(), collision.y())
+}
+
+fn part_2(grid: &Grid) -> String {
+    let position = grid.carts[0].turtle.position;
+    format!("({}, {})", position.x(), position.y())
+}
+
+fn parse_input(input: &str) -> Grid {
+    let mut cells = HashMap::new();
+    let mut carts = Vec::new();
+
+    for (y, line) in input.lines().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            let position = Vector::new([x as i32, y as i32]);
+            if matches!(c, '^' | '>' | 'v' | '<') {
+                carts.push(Cart::new(Direction4::try_from(c).unwrap(), position));
+            }
+            if let Some(cell) = Cell::parse_from(c) {
+                cells.insert(position, cell);
+            }
+        }
+    }
+
+    Grid {
+        cells,
+        carts,
+        collisions: vec![],
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Grid {
+    cells: HashMap<Vector<2>, Cell>,
+    carts: Vec<Cart>,
+    collisions: Vec<Vector<2>>,
+}
+
+impl Grid {
+    fn run_until_final_cart(&mut self) {
+        loop {
+            // sort carts so that we go through them L to R, top to bottom
+            self.carts
+                .sort_by_key(|c| (c.turtle.position.y(), c.turtle.position.x()));
+
+            for idx in 0..self.carts.len() {
+                self.carts[idx].tick(&self.cells);
+
+                let curr_position = self.carts[idx].turtle.position;
+                let collisions = self
+                    .carts
+                    .iter_mut()
+                    .filter(|c| c.turtle.position == curr_position)
+                    .collect_vec();
+
+                if collisions.len() >= 2 {
+                    self.collisions.push(curr_position);
+                    for collision in collisions {
+                        collision.collided = true;
+                    }
+                    self.carts[idx].collided = true;
+                }
+            }
+
+            self.carts.retain(|c| !c.collided);
+            if self.carts.len() == 1 {
+                break;
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Cart {
+    turtle: Turtle,
+    next_junction_direction: JunctionMovement,
+    collided: bool,
+}
+
+impl Cart {
+    fn new(direction: Direction4, position: Vector<2>) -> Self {
+        Cart {
+            turtle: Turtle::new(position, direction),
+            next_junction_direction: JunctionMovement::Left,
+            collided: false,
+        }
+    }
+
+    fn tick(&mut self, map: &HashMap<Vector<2>, Cell>) {
+        let turtle = &mut self.turtle;
+
+        // move first then set up for next movement
+        turtle.forward(1);
+
+        let vertical = matches!(turtle.heading, Direction4::Up | Direction4::Down);
+        match map.get(&turtle.position).expect("Track ends abruptly!") {
+            Cell::Crossroads => {
+                match self.next_junction_direction {
+                    JunctionMovement::StraightOn => {}
+                    JunctionMovement::Left => turtle.turn_left(),
+                    JunctionMovement::Right => turtle.turn_right(),
+                };
+                self.next_junction_direction.next();
+            }
+            Cell::ForwardSlash if vertical => turtle.turn_right(),
+            Cell::ForwardSlash => turtle.turn_left(),
+            Cell::BackSlash if vertical => turtle.turn_left(),
+            Cell::BackSlash => turtle.turn_right(),
+            _ => {}
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+enum JunctionMovement {
+    Left,
+    StraightOn,
+    Right,
+}
+
+impl JunctionMovement {
+    fn next(&mut self) {
+        use JunctionMovement::*;
+        *self = match self {
+            Left => StraightOn,
+            StraightOn => Right,
+            Right => Left,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+enum Cell {
+    Horizontal,
+    Vertical,
+    Crossroads,
+    ForwardSlash,
+    BackSlash,
+}
+
+impl Cell {
+    fn parse_from(x: char) -> Option<Self> {
+        use Cell::*;
+        Some(match x {
+            '-' | '>' | '<' => Horizontal,
+            '|' | '^' | 'v' => Vertical,
+            '+' => Crossroads,
+            '/' => ForwardSlash,
+            '\\' => BackSlash,
+            _ => return None,
+        })
+    }
+}
@@ -1,5 +1,16 @@
-use aoc_lib::get_input_year;
+// used in day_09
+#![feature(linked_list_cursors)]
+// used in day_15
+#![feature(let_chains)]
+
+pub mod days;
+
+use aoc_lib::{try_get_input_year, AocError};
+
+pub fn try_get_input(day: usize) -> Result<String, AocError> {
+    try_get_input_year(2018, day)
+}
 
 pub fn get_input(day: usize) -> String {
-    get_input_year(2018, day)
+    try_get_input(day).unwrap_or_else(|e| panic!("{e}"))
 }
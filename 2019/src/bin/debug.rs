@@ -0,0 +1,34 @@
+use aoc_2019::get_input;
+use aoc_2019::intcode::{parse_input, Debugger, Machine};
+use std::io::{self, BufRead, Write};
+
+/// Steps through a day's Intcode program, like `cargo run --bin debug 9 1` to start day 9 with
+/// the input 1. Type `h` for the commands.
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let day = args
+        .next()
+        .and_then(|day| day.parse().ok())
+        .expect("Usage: debug <day> [inputs...]");
+    let mut machine = Machine::new(parse_input(get_input(day).trim()));
+    machine.inputs = args
+        .map(|input| input.parse().expect("Inputs should be numbers"))
+        .collect();
+    let mut debugger = Debugger::new(machine);
+
+    let mut lines = io::stdin().lock().lines();
+    loop {
+        print!("> ");
+        io::stdout().flush().unwrap();
+        let Some(Ok(line)) = lines.next() else {
+            break;
+        };
+        match line.trim() {
+            "q" | "quit" => break,
+            command => match debugger.command(command) {
+                Ok(output) => println!("{output}"),
+                Err(error) => println!("{error}"),
+            },
+        }
+    }
+}
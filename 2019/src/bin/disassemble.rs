@@ -0,0 +1,11 @@
+use aoc_2019::get_input;
+use aoc_2019::intcode::{disassemble, parse_input};
+
+/// Prints the listing of a day's Intcode program, like `cargo run --bin disassemble 9`
+fn main() {
+    let day = std::env::args()
+        .nth(1)
+        .and_then(|day| day.parse().ok())
+        .expect("Usage: disassemble <day>");
+    print!("{}", disassemble(&parse_input(get_input(day).trim())));
+}
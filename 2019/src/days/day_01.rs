@@ -0,0 +1,57 @@
+use aoc_lib::solution::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: usize = 2019;
+    const DAY: usize = 1;
+    const TITLE: &'static str = "The Tyranny of the Rocket Equation";
+
+    type Parsed<'a> = Vec<u32>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+            .lines()
+            .map(|line| line.parse::<u32>().expect("Valid number in input"))
+            .collect()
+    }
+
+    fn part_1(module_masses: &Self::Parsed<'_>) -> u32 {
+        part_1(module_masses)
+    }
+
+    fn part_2(module_masses: &Self::Parsed<'_>) -> u32 {
+        part_2(module_masses)
+    }
+}
+
+fn part_1(module_masses: &[u32]) -> u32 {
+    module_masses.iter().copied().map(calculate_fuel).sum()
+}
+
+fn part_2(module_masses: &[u32]) -> u32 {
+    module_masses
+        .iter()
+        .copied()
+        .map(calculate_fuel_recursive)
+        .sum()
+}
+
+fn calculate_fuel(mass: u32) -> u32 {
+    if mass == 0 {
+        return 0;
+    }
+    (mass / 3).saturating_sub(2)
+}
+
+fn calculate_fuel_recursive(mass: u32) -> u32 {
+    let mut fuel = 0;
+    let mut last_weight_added = mass;
+    while last_weight_added > 0 {
+        last_weight_added = calculate_fuel(last_weight_added);
+        fuel += last_weight_added;
+    }
+    fuel
+}
@@ -0,0 +1,84 @@
+use aoc_lib::solution::Solution;
+use itertools::Itertools;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: usize = 2019;
+    const DAY: usize = 4;
+    const TITLE: &'static str = "Secure Container";
+
+    type Parsed<'a> = (u32, u32);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        let (lower, upper) = input
+            .trim()
+            .split_once("-")
+            .expect("Input in format xxxx-yyyy");
+        (
+            lower.parse().expect("Lower limit is valid number"),
+            upper.parse().expect("Upper limit is valid number"),
+        )
+    }
+
+    fn part_1((lower, upper): &Self::Parsed<'_>) -> usize {
+        (*lower..=*upper)
+            .map(to_digits)
+            .filter(|x| is_increasing(x))
+            .filter(|x| has_double(x))
+            .count()
+    }
+
+    fn part_2((lower, upper): &Self::Parsed<'_>) -> usize {
+        (*lower..=*upper)
+            .map(to_digits)
+            .filter(|x| is_increasing(x))
+            .filter(|x| has_only_double(x))
+            .count()
+    }
+}
+
+// quicker than converting to string and splitting into characters
+fn to_digits(mut number: u32) -> Vec<u8> {
+    let mut digits: Vec<u8> = Vec::with_capacity(6);
+
+    while number > 0 {
+        let n = (number % 10) as u8;
+        number /= 10;
+        digits.push(n);
+    }
+
+    digits.reverse();
+    digits
+}
+
+fn has_double(input: &[u8]) -> bool {
+    for (left, right) in input.iter().tuple_windows() {
+        if *left == *right {
+            return true;
+        }
+    }
+    false
+}
+
+// We assume the slice is increasing, so we can just count occurrences of each digit
+fn has_only_double(input: &[u8]) -> bool {
+    input
+        .into_iter()
+        .counts()
+        .into_iter()
+        .any(|(_digit, count)| count == 2)
+}
+
+fn is_increasing(input: &[u8]) -> bool {
+    let mut current_digit = 0;
+    for digit in input.into_iter() {
+        if *digit < current_digit {
+            return false;
+        }
+        current_digit = *digit;
+    }
+    true
+}
@@ -0,0 +1,90 @@
+use aoc_lib::solution::Solution;
+use itertools::Itertools;
+use pathfinding::prelude::dijkstra;
+use std::collections::HashMap;
+
+const CENTRE: &str = "COM";
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: usize = 2019;
+    const DAY: usize = 6;
+    const TITLE: &'static str = "Universal Orbit Map";
+
+    type Parsed<'a> = (
+        HashMap<&'a str, Vec<&'a str>>,
+        HashMap<&'a str, Vec<&'a str>>,
+    );
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        let mut orbits = HashMap::<&str, Vec<&str>>::new();
+        let mut inverse_orbits = HashMap::<&str, Vec<&str>>::new();
+
+        for line in input.lines() {
+            let s = line
+                .split_once(")")
+                .expect(&format!("Invalid input: {line}"));
+            orbits.entry(s.0).or_default().push(s.1);
+            inverse_orbits.entry(s.1).or_default().push(s.0);
+        }
+
+        (orbits, inverse_orbits)
+    }
+
+    fn part_1((orbits, _inverse_orbits): &Self::Parsed<'_>) -> usize {
+        part_1(orbits)
+    }
+
+    fn part_2((orbits, inverse_orbits): &Self::Parsed<'_>) -> usize {
+        part_2(orbits, inverse_orbits)
+    }
+}
+
+fn part_1(orbits: &HashMap<&str, Vec<&str>>) -> usize {
+    let mut count = 0;
+    recurse_count(&orbits, CENTRE, 1, &mut count);
+    count
+}
+
+fn part_2(orbits: &HashMap<&str, Vec<&str>>, inverse_orbits: &HashMap<&str, Vec<&str>>) -> usize {
+    find_shortest_path("YOU", "SAN", orbits, inverse_orbits)
+        .expect("Unable to find a path")
+        .1
+        - 2
+}
+
+fn find_shortest_path<'a>(
+    start: &'a str,
+    end: &str,
+    orbits: &'a HashMap<&str, Vec<&str>>,
+    inverse_orbits: &'a HashMap<&str, Vec<&str>>,
+) -> Option<(Vec<&'a str>, usize)> {
+    dijkstra(
+        &start,
+        |x| {
+            orbits
+                .get(x)
+                .unwrap_or(&vec![])
+                .into_iter()
+                .chain(inverse_orbits.get(x).unwrap_or(&vec![]))
+                .map(|y| (*y, 1))
+                .collect_vec()
+        },
+        |x| *x == end,
+    )
+}
+
+fn recurse_count(
+    direct_orbits: &HashMap<&str, Vec<&str>>,
+    current: &str,
+    depth: usize,
+    count: &mut usize,
+) {
+    for orbitee in direct_orbits.get(current).unwrap_or(&vec![]) {
+        *count += depth;
+        recurse_count(direct_orbits, orbitee, depth + 1, count);
+    }
+}
@@ -0,0 +1,59 @@
+use crate::intcode::{parse_input, Network, Routing};
+use aoc_lib::solution::Solution;
+use itertools::Itertools;
+use std::iter::repeat_n;
+use std::ops::RangeInclusive;
+
+const MACHINE_COUNT: usize = 5;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: usize = 2019;
+    const DAY: usize = 7;
+    const TITLE: &'static str = "Amplification Circuit";
+
+    type Parsed<'a> = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_1(instructions: &Self::Parsed<'_>) -> i64 {
+        part_1(instructions)
+    }
+
+    fn part_2(instructions: &Self::Parsed<'_>) -> i64 {
+        part_2(instructions)
+    }
+}
+
+fn part_1(instructions: &[i64]) -> i64 {
+    max_signal(instructions, 0..=4)
+}
+
+fn part_2(instructions: &[i64]) -> i64 {
+    max_signal(instructions, 5..=9)
+}
+
+/// The amplifiers are wired in a loop, which only matters for the phases that make them feed
+/// back rather than halt after a single output
+fn max_signal(instructions: &[i64], phases: RangeInclusive<i64>) -> i64 {
+    phases
+        .permutations(MACHINE_COUNT)
+        .map(|phases| {
+            let programs = repeat_n(instructions.to_vec(), MACHINE_COUNT);
+            let mut network = Network::new(programs, Routing::Ring);
+            for (address, phase) in phases.into_iter().enumerate() {
+                network.send(address, [phase]);
+            }
+            network.send(0, [0]);
+            network.run().unwrap();
+            // the last amplifier's signal goes round to the first, which has halted by then
+            network.machines()[0].inputs.pop().unwrap()
+        })
+        .max()
+        .unwrap()
+}
@@ -0,0 +1,109 @@
+use aoc_lib::solution::Solution;
+use itertools::Itertools;
+
+const HEIGHT: usize = 6;
+const WIDTH: usize = 25;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: usize = 2019;
+    const DAY: usize = 8;
+    const TITLE: &'static str = "Space Image Format";
+
+    type Parsed<'a> = Vec<Vec<char>>;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+            .trim()
+            .chars()
+            .chunks(HEIGHT * WIDTH)
+            .into_iter()
+            .map(|x| x.collect_vec())
+            .collect_vec()
+    }
+
+    fn part_1(layers: &Self::Parsed<'_>) -> usize {
+        part_1(layers)
+    }
+
+    fn part_2(layers: &Self::Parsed<'_>) -> String {
+        part_2(layers)
+    }
+}
+
+fn part_1(layers: &Vec<Vec<char>>) -> usize {
+    let fewest_zeros_layer = layers
+        .iter()
+        .min_by_key(|layer| layer.into_iter().filter(|x| **x == '0').count())
+        .unwrap();
+
+    let counts = fewest_zeros_layer.into_iter().counts();
+    counts.get(&'1').unwrap_or(&0) * counts.get(&'2').unwrap_or(&0)
+}
+
+fn part_2(layers: &Vec<Vec<char>>) -> String {
+    let canvas = collapse_layers(layers);
+    render_canvas(&canvas)
+}
+
+fn collapse_layers(layers: &Vec<Vec<char>>) -> Vec<Colour> {
+    let mut canvas = layers
+        .last()
+        .unwrap()
+        .iter()
+        .map(|c| Colour::from_char(*c))
+        .collect_vec();
+
+    for layer in layers.iter().rev() {
+        for (i, c) in layer.iter().enumerate() {
+            use Colour::*;
+            canvas[i] = match (canvas[i], Colour::from_char(*c)) {
+                (_, colour @ Black) => colour,
+                (_, colour @ White) => colour,
+                (colour, Transparent) => colour,
+            }
+        }
+    }
+
+    canvas
+}
+
+fn render_canvas(canvas: &[Colour]) -> String {
+    canvas
+        .iter()
+        .chunks(WIDTH)
+        .into_iter()
+        .map(|row| row.map(|colour| colour.to_ascii()).join(""))
+        .join("\n")
+}
+
+#[derive(Copy, Clone, Debug)]
+enum Colour {
+    Black,
+    White,
+    Transparent,
+}
+
+impl Colour {
+    fn from_char(input: char) -> Colour {
+        use Colour::*;
+        match input {
+            '0' => Black,
+            '1' => White,
+            '2' => Transparent,
+            x => panic!("Invalid colour code: {x}"),
+        }
+    }
+
+    fn to_ascii(&self) -> char {
+        use Colour::*;
+        match self {
+            Black => ' ',
+            White => '█',
+            Transparent => ' ',
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::intcode::{parse_input, Machine};
+use aoc_lib::solution::Solution;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: usize = 2019;
+    const DAY: usize = 9;
+    const TITLE: &'static str = "Sensor Boost";
+
+    type Parsed<'a> = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_1(instructions: &Self::Parsed<'_>) -> i64 {
+        let mut machine = Machine::new(instructions.clone());
+        machine.inputs.push_back(1);
+        machine.run().unwrap();
+        *machine.outputs.last().unwrap()
+    }
+
+    fn part_2(instructions: &Self::Parsed<'_>) -> i64 {
+        let mut machine = Machine::new(instructions.clone());
+        machine.inputs.push_back(2);
+        machine.run().unwrap();
+        *machine.outputs.last().unwrap()
+    }
+}
+
+#[test]
+fn test_quine() {
+    let instructions = vec![
+        109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
+    ];
+    let mut machine = Machine::new(instructions.clone());
+    machine.run().unwrap();
+    assert_eq!(instructions, machine.outputs);
+}
+
+#[test]
+fn test_16digit() {
+    let instructions = vec![1102, 34915192, 34915192, 7, 4, 7, 99, 0];
+    let mut machine = Machine::new(instructions.clone());
+    machine.run().unwrap();
+    assert_eq!(vec![1219070632396864], machine.outputs);
+}
+
+#[test]
+fn test_output_num() {
+    let instructions = vec![104, 1125899906842624, 99];
+    let mut machine = Machine::new(instructions.clone());
+    machine.run().unwrap();
+    assert_eq!(vec![instructions[1]], machine.outputs);
+}
@@ -1,7 +1,22 @@
+pub use assembler::assemble;
+pub use debugger::{Breakpoint, Debugger, Stop, Trace};
+pub use disassembler::{disassemble, disassemble_from, Line, Listing, Operand};
+pub use error::IntcodeError;
+pub use io::{Channel, InputSource, OutputSink};
+pub use network::{Event, Network, Packet, Routing};
+use std::collections::VecDeque;
 use types::*;
+pub use types::{InstructionType, OperandType};
+
+mod assembler;
+mod debugger;
+mod disassembler;
+mod error;
+mod io;
+mod network;
 
 mod types {
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
     pub enum InstructionType {
         Add,
         Multiply,
@@ -16,9 +31,9 @@ mod types {
     }
 
     impl InstructionType {
-        pub fn parse(number: i64) -> InstructionType {
+        pub fn try_parse(number: i64) -> Option<InstructionType> {
             use InstructionType::*;
-            match number % 100 {
+            Some(match number % 100 {
                 1 => Add,
                 2 => Multiply,
                 3 => Input,
@@ -29,18 +44,96 @@ mod types {
                 8 => Equals,
                 9 => AdjustBase,
                 99 => Stop,
-                n => panic!("Invalid instruction type: {n}"),
+                _ => return None,
+            })
+        }
+
+        pub fn from_mnemonic(mnemonic: &str) -> Option<InstructionType> {
+            use InstructionType::*;
+            Some(match mnemonic {
+                "add" => Add,
+                "mul" => Multiply,
+                "in" => Input,
+                "out" => Output,
+                "jt" => JumpIfTrue,
+                "jf" => JumpIfFalse,
+                "lt" => LessThan,
+                "eq" => Equals,
+                "arb" => AdjustBase,
+                "hlt" => Stop,
+                _ => return None,
+            })
+        }
+
+        pub fn opcode(self) -> i64 {
+            use InstructionType::*;
+            match self {
+                Add => 1,
+                Multiply => 2,
+                Input => 3,
+                Output => 4,
+                JumpIfTrue => 5,
+                JumpIfFalse => 6,
+                LessThan => 7,
+                Equals => 8,
+                AdjustBase => 9,
+                Stop => 99,
+            }
+        }
+
+        pub fn operand_count(self) -> usize {
+            use InstructionType::*;
+            match self {
+                Add | Multiply | LessThan | Equals => 3,
+                JumpIfTrue | JumpIfFalse => 2,
+                Input | Output | AdjustBase => 1,
+                Stop => 0,
+            }
+        }
+
+        pub fn mnemonic(self) -> &'static str {
+            use InstructionType::*;
+            match self {
+                Add => "add",
+                Multiply => "mul",
+                Input => "in",
+                Output => "out",
+                JumpIfTrue => "jt",
+                JumpIfFalse => "jf",
+                LessThan => "lt",
+                Equals => "eq",
+                AdjustBase => "arb",
+                Stop => "hlt",
             }
         }
     }
 
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
     pub enum OperandType {
         Position,
         Immediate,
         Relative,
     }
 
+    impl OperandType {
+        pub fn try_parse(digit: i64) -> Option<OperandType> {
+            match digit {
+                0 => Some(OperandType::Position),
+                1 => Some(OperandType::Immediate),
+                2 => Some(OperandType::Relative),
+                _ => None,
+            }
+        }
+
+        pub fn digit(self) -> i64 {
+            match self {
+                OperandType::Position => 0,
+                OperandType::Immediate => 1,
+                OperandType::Relative => 2,
+            }
+        }
+    }
+
     #[derive(Debug)]
     pub struct Instruction {
         pub modes: Vec<OperandType>,
@@ -48,30 +141,8 @@ mod types {
     }
 }
 
-fn extract_operand_type(number: i64, position: usize) -> OperandType {
-    match (number as usize / 10_usize.pow((position + 2) as u32)) % 10 {
-        0 => OperandType::Position,
-        1 => OperandType::Immediate,
-        2 => OperandType::Relative,
-        n => panic!("Invalid OperandType: {n}"),
-    }
-}
-
-fn extract_operand_types(mut number: i64) -> Vec<OperandType> {
-    number /= 100;
-    let mut types = vec![];
-
-    while number > 0 {
-        types.push(match number % 10 {
-            0 => OperandType::Position,
-            1 => OperandType::Immediate,
-            2 => OperandType::Relative,
-            n => panic!("Invalid OperandType: {n}"),
-        });
-        number /= 10;
-    }
-
-    types
+fn extract_operand_type(number: i64, position: usize) -> Option<OperandType> {
+    OperandType::try_parse((number / 10_i64.pow((position + 2) as u32)) % 10)
 }
 
 pub fn parse_input(input: &str) -> Vec<i64> {
@@ -81,7 +152,10 @@ pub fn parse_input(input: &str) -> Vec<i64> {
         .collect()
 }
 
-#[derive(Copy, Clone, Debug)]
+/// Writes past this many words of memory are faults rather than attempts to allocate it all
+pub const MEMORY_LIMIT: usize = 1 << 24;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum MachineState {
     NotStarted,
     Running,
@@ -89,87 +163,176 @@ pub enum MachineState {
     HitStopInstruction,
 }
 
+/// An Intcode computer, reading its inputs from a queue and collecting its outputs unless
+/// given other I/O with [`Machine::with_io`]
 #[derive(Clone)]
-pub struct Machine {
+pub struct Machine<I = VecDeque<i64>, O = Vec<i64>> {
     pub program: Vec<i64>,
     program_cursor: usize,
-    pub inputs: Vec<i64>,
-    input_cursor: usize,
+    pub inputs: I,
     pub state: MachineState,
-    pub outputs: Vec<i64>,
+    pub outputs: O,
     pub debug: bool,
     output_debug: Vec<(usize, InstructionType, Vec<OperandType>, i64, i64)>,
     relative_base: i64,
+    last_write: Option<(usize, i64)>,
 }
 
 impl Machine {
     pub fn new(instructions: Vec<i64>) -> Self {
+        Self::with_io(instructions, VecDeque::new(), vec![])
+    }
+}
+
+impl<I: InputSource, O: OutputSink> Machine<I, O> {
+    pub fn with_io(instructions: Vec<i64>, inputs: I, outputs: O) -> Self {
         Self {
             program: instructions,
             program_cursor: 0,
-            inputs: vec![],
-            input_cursor: 0,
+            inputs,
             state: MachineState::NotStarted,
-            outputs: vec![],
+            outputs,
             debug: false,
             output_debug: vec![],
             relative_base: 0,
+            last_write: None,
         }
     }
 
-    fn read_instruction(&self) -> Instruction {
-        Instruction {
-            r#type: InstructionType::parse(self.program[self.program_cursor]),
-            modes: extract_operand_types(self.program[self.program_cursor]),
+    /// The address of the next instruction to run
+    pub fn cursor(&self) -> usize {
+        self.program_cursor
+    }
+
+    pub fn relative_base(&self) -> i64 {
+        self.relative_base
+    }
+
+    /// The memory at `address`, which is 0 past the end of the program
+    pub fn read(&self, address: usize) -> i64 {
+        self.program.get(address).copied().unwrap_or(0)
+    }
+
+    /// The address and value written by the last step, if it wrote anything
+    pub fn last_write(&self) -> Option<(usize, i64)> {
+        self.last_write
+    }
+
+    /// The next instruction to run, or `None` if the cursor isn't on a valid instruction
+    pub fn current_instruction(&self) -> Option<Line> {
+        Line::decode(&self.program, self.program_cursor)
+    }
+
+    fn read_instruction(&self) -> Result<Instruction, IntcodeError> {
+        let number = self.read(self.program_cursor);
+        let r#type = InstructionType::try_parse(number).ok_or(IntcodeError::InvalidOpcode {
+            address: self.program_cursor,
+            instruction: number,
+        })?;
+        let modes = (0..r#type.operand_count())
+            .map(|parameter| self.operand_type(parameter))
+            .collect::<Result<_, _>>()?;
+        Ok(Instruction { modes, r#type })
+    }
+
+    fn operand_type(&self, parameter: usize) -> Result<OperandType, IntcodeError> {
+        let instruction = self.read(self.program_cursor);
+        extract_operand_type(instruction, parameter).ok_or(IntcodeError::InvalidMode {
+            address: self.program_cursor,
+            instruction,
+            parameter,
+        })
+    }
+
+    /// The address a position or relative mode parameter points at
+    fn operand_address(&self, parameter: usize) -> Result<usize, IntcodeError> {
+        let operand = self.read(self.program_cursor + parameter + 1);
+        let target = match self.operand_type(parameter)? {
+            OperandType::Position => operand,
+            OperandType::Relative => operand
+                .checked_add(self.relative_base)
+                .ok_or_else(|| self.overflow())?,
+            OperandType::Immediate => {
+                return Err(IntcodeError::WriteToImmediate {
+                    address: self.program_cursor,
+                    instruction: self.read(self.program_cursor),
+                    parameter,
+                })
+            }
+        };
+        usize::try_from(target).map_err(|_| IntcodeError::NegativeAddress {
+            address: self.program_cursor,
+            instruction: self.read(self.program_cursor),
+            target,
+        })
+    }
+
+    fn get_value(&self, parameter_index: usize) -> Result<i64, IntcodeError> {
+        match self.operand_type(parameter_index)? {
+            OperandType::Immediate => Ok(self.read(self.program_cursor + parameter_index + 1)),
+            _ => Ok(self.read(self.operand_address(parameter_index)?)),
         }
     }
 
-    fn get_value(&self, parameter_index: usize) -> i64 {
-        let arg_offset = self.program_cursor + parameter_index + 1;
-        match extract_operand_type(self.program[self.program_cursor], parameter_index) {
-            OperandType::Position => *self
-                .program
-                .get(self.program[arg_offset] as usize)
-                .unwrap_or(&0),
-            OperandType::Immediate => self.program[arg_offset],
-            OperandType::Relative => *self
-                .program
-                .get((self.program[arg_offset] + (self.relative_base)) as usize)
-                .unwrap_or(&0),
+    /// The address a parameter that's written to points at, within the [`MEMORY_LIMIT`]
+    fn write_address(&self, parameter: usize) -> Result<usize, IntcodeError> {
+        let target = self.operand_address(parameter)?;
+        if target >= MEMORY_LIMIT {
+            return Err(IntcodeError::OutOfMemory {
+                address: self.program_cursor,
+                instruction: self.read(self.program_cursor),
+                target,
+            });
         }
+        Ok(target)
     }
 
-    fn set_value(&mut self, parameter_index: usize, value: i64) {
-        let arg_offset = self.program_cursor + parameter_index + 1;
-        match extract_operand_type(self.program[self.program_cursor], parameter_index) {
-            OperandType::Position => {
-                let offset = self.program[arg_offset];
-                if self.program.len() <= offset as usize {
-                    self.program.resize(offset as usize + 1, 0);
-                }
-                self.program[offset as usize] = value;
-            }
-            OperandType::Immediate => panic!("Can not set immediate mode value"),
-            OperandType::Relative => {
-                let offset = self.program[arg_offset];
-                let offset = (offset + self.relative_base) as usize;
-                if self.program.len() <= offset {
-                    self.program.resize(offset + 1, 0);
-                }
-                self.program[offset] = value;
+    fn set_value(&mut self, parameter_index: usize, value: i64) -> Result<(), IntcodeError> {
+        let address = self.write_address(parameter_index)?;
+        self.write(address, value);
+        Ok(())
+    }
+
+    fn write(&mut self, address: usize, value: i64) {
+        if self.program.len() <= address {
+            self.program.resize(address + 1, 0);
+        }
+        self.program[address] = value;
+        self.last_write = Some((address, value));
+    }
+
+    fn overflow(&self) -> IntcodeError {
+        IntcodeError::Overflow {
+            address: self.program_cursor,
+            instruction: self.read(self.program_cursor),
+        }
+    }
+
+    fn jump(&mut self, parameter_index: usize) -> Result<(), IntcodeError> {
+        let target = self.get_value(parameter_index)?;
+        match usize::try_from(target) {
+            Ok(target) if target < self.program.len() => {
+                self.program_cursor = target;
+                Ok(())
             }
+            _ => Err(IntcodeError::JumpOutOfBounds {
+                address: self.program_cursor,
+                instruction: self.read(self.program_cursor),
+                target,
+            }),
         }
     }
 
-    pub fn run(&mut self) -> MachineState {
+    /// Runs until it needs more input or halts
+    pub fn run(&mut self) -> Result<MachineState, IntcodeError> {
         use MachineState::*;
         loop {
-            match self.step() {
+            match self.step()? {
                 NotStarted => continue,
                 Running => continue,
                 NeedMoreInput => break,
                 HitStopInstruction => {
-                    if self.debug && self.outputs.len() > 1 {
+                    if self.debug && self.output_debug.len() > 1 {
                         println!("Checks failed:");
                         for (
                             cursor,
@@ -194,71 +357,77 @@ impl Machine {
                 }
             }
         }
-        self.state
+        Ok(self.state)
     }
 
-    fn step(&mut self) -> MachineState {
+    /// Runs a single instruction, unless it's waiting for input or has stopped. A faulting
+    /// instruction is left where it is, with nothing changed.
+    pub fn step(&mut self) -> Result<MachineState, IntcodeError> {
+        let (state, last_write) = (self.state, self.last_write);
+        let result = self.execute();
+        if result.is_err() {
+            self.state = state;
+            self.last_write = last_write;
+        }
+        result
+    }
+
+    /// Every fault is found before memory, the cursor or the I/O is touched, leaving only the
+    /// state and last write for [`Machine::step`] to put back
+    fn execute(&mut self) -> Result<MachineState, IntcodeError> {
         self.state = MachineState::Running;
+        self.last_write = None;
 
-        let instruction = self.read_instruction();
+        let instruction = self.read_instruction()?;
         match instruction.r#type {
             InstructionType::Add => {
-                let (left, right) = (self.get_value(0), self.get_value(1));
-                self.set_value(2, left + right);
+                let (left, right) = (self.get_value(0)?, self.get_value(1)?);
+                let sum = left.checked_add(right).ok_or_else(|| self.overflow())?;
+                self.set_value(2, sum)?;
                 self.program_cursor += 4;
             }
             InstructionType::Multiply => {
-                let (left, right) = (self.get_value(0), self.get_value(1));
-                self.set_value(2, left * right);
+                let (left, right) = (self.get_value(0)?, self.get_value(1)?);
+                let product = left.checked_mul(right).ok_or_else(|| self.overflow())?;
+                self.set_value(2, product)?;
                 self.program_cursor += 4;
             }
             InstructionType::JumpIfTrue => {
-                if self.get_value(0) != 0 {
-                    self.program_cursor = self.get_value(1) as usize;
+                if self.get_value(0)? != 0 {
+                    self.jump(1)?;
                 } else {
                     self.program_cursor += 3;
                 }
             }
             InstructionType::JumpIfFalse => {
-                if self.get_value(0) == 0 {
-                    self.program_cursor = self.get_value(1) as usize;
+                if self.get_value(0)? == 0 {
+                    self.jump(1)?;
                 } else {
                     self.program_cursor += 3;
                 }
             }
             InstructionType::LessThan => {
-                self.set_value(
-                    2,
-                    if self.get_value(0) < self.get_value(1) {
-                        1
-                    } else {
-                        0
-                    },
-                );
+                let (left, right) = (self.get_value(0)?, self.get_value(1)?);
+                self.set_value(2, if left < right { 1 } else { 0 })?;
                 self.program_cursor += 4;
             }
             InstructionType::Equals => {
-                self.set_value(
-                    2,
-                    if self.get_value(0) == self.get_value(1) {
-                        1
-                    } else {
-                        0
-                    },
-                );
+                let (left, right) = (self.get_value(0)?, self.get_value(1)?);
+                self.set_value(2, if left == right { 1 } else { 0 })?;
                 self.program_cursor += 4;
             }
             InstructionType::Input => {
-                if self.inputs.get(self.input_cursor).is_none() {
+                // check the address first, so a fault doesn't lose the input
+                let address = self.write_address(0)?;
+                let Some(input) = self.inputs.read() else {
                     self.state = MachineState::NeedMoreInput;
-                    return self.state;
-                }
-                self.set_value(0, self.inputs[self.input_cursor]);
+                    return Ok(self.state);
+                };
+                self.write(address, input);
                 self.program_cursor += 2;
-                self.input_cursor += 1;
             }
             InstructionType::Output => {
-                let output = self.get_value(0);
+                let output = self.get_value(0)?;
                 self.output_debug.push((
                     self.program_cursor,
                     instruction.r#type,
@@ -266,11 +435,14 @@ impl Machine {
                     self.program[self.program_cursor],
                     output,
                 ));
-                self.outputs.push(output);
+                self.outputs.write(output);
                 self.program_cursor += 2;
             }
             InstructionType::AdjustBase => {
-                self.relative_base += self.get_value(0);
+                self.relative_base = self
+                    .relative_base
+                    .checked_add(self.get_value(0)?)
+                    .ok_or_else(|| self.overflow())?;
                 self.program_cursor += 2;
             }
             InstructionType::Stop => {
@@ -278,6 +450,6 @@ impl Machine {
                 // don't advance cursor to make sure we continually hit stop to avoid overrunning
             }
         }
-        self.state
+        Ok(self.state)
     }
 }
@@ -0,0 +1,253 @@
+use super::{InstructionType, Line, Operand, OperandType};
+use std::collections::HashMap;
+
+enum Statement<'a> {
+    Instruction(InstructionType, Vec<&'a str>),
+    Data(Vec<&'a str>),
+}
+
+impl Statement<'_> {
+    fn size(&self) -> usize {
+        match self {
+            Statement::Instruction(_, operands) => 1 + operands.len(),
+            Statement::Data(values) => values.len(),
+        }
+    }
+}
+
+/// Turns source like the [`Listing`](super::Listing)s the disassembler writes back into a
+/// program.
+///
+/// Each line holds an instruction such as `add [rb+1], #3, [100]`, or a `data` directive
+/// followed by its words, and anything after a `;` is a comment. A line can start with a label
+/// like `loop:`, which stands for its address wherever a number can go, optionally with an
+/// offset like `loop+2`. A number as the label, like `12:`, checks the address instead.
+pub fn assemble(source: &str) -> Result<Vec<i64>, String> {
+    let mut labels = HashMap::new();
+    let mut statements = vec![];
+    let mut address = 0;
+
+    for (index, line) in source.lines().enumerate() {
+        let error = |message| format!("Line {}: {message}", index + 1);
+        let mut line = line.split(';').next().unwrap_or_default().trim();
+
+        while let Some((label, rest)) = line.split_once(':') {
+            let label = label.trim();
+            if let Ok(expected) = label.parse::<usize>() {
+                if expected != address {
+                    return Err(error(format!("Expected address {expected}, at {address}")));
+                }
+            } else if !is_label(label) {
+                return Err(error(format!("Invalid label: {label:?}")));
+            } else if labels.insert(label, address as i64).is_some() {
+                return Err(error(format!("Duplicate label: {label}")));
+            }
+            line = rest.trim();
+        }
+        if line.is_empty() {
+            continue;
+        }
+
+        let (mnemonic, operands) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        let operands = match operands.trim() {
+            "" => vec![],
+            operands => operands.split(',').map(str::trim).collect(),
+        };
+        let statement = if mnemonic == "data" {
+            Statement::Data(operands)
+        } else {
+            let r#type = InstructionType::from_mnemonic(mnemonic)
+                .ok_or_else(|| error(format!("Unknown mnemonic: {mnemonic}")))?;
+            if operands.len() != r#type.operand_count() {
+                return Err(error(format!(
+                    "{mnemonic} takes {} operands, not {}",
+                    r#type.operand_count(),
+                    operands.len()
+                )));
+            }
+            Statement::Instruction(r#type, operands)
+        };
+        address += statement.size();
+        statements.push((index, statement));
+    }
+
+    let mut program = Vec::with_capacity(address);
+    for (index, statement) in statements {
+        let error = |message| format!("Line {}: {message}", index + 1);
+        let line = match statement {
+            Statement::Instruction(r#type, operands) => Line::Instruction {
+                address: program.len(),
+                r#type,
+                operands: operands
+                    .into_iter()
+                    .map(|operand| parse_operand(operand, &labels))
+                    .collect::<Result<_, _>>()
+                    .map_err(error)?,
+            },
+            Statement::Data(values) => Line::Data {
+                address: program.len(),
+                values: values
+                    .into_iter()
+                    .map(|value| evaluate(value, &labels))
+                    .collect::<Result<_, _>>()
+                    .map_err(error)?,
+            },
+        };
+        program.extend(line.words());
+    }
+    Ok(program)
+}
+
+fn is_label(s: &str) -> bool {
+    s.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// `#imm`, `[addr]` or `[rb+n]`
+fn parse_operand(s: &str, labels: &HashMap<&str, i64>) -> Result<Operand, String> {
+    let (mode, value) = if let Some(value) = s.strip_prefix('#') {
+        (OperandType::Immediate, evaluate(value, labels)?)
+    } else if let Some(inner) = s.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+        let inner = inner.trim();
+        match inner.strip_prefix("rb").map(str::trim_start) {
+            Some("") => (OperandType::Relative, 0),
+            Some(offset) if offset.starts_with(['+', '-']) => {
+                (OperandType::Relative, evaluate(offset, labels)?)
+            }
+            _ => (OperandType::Position, evaluate(inner, labels)?),
+        }
+    } else {
+        return Err(format!("Invalid operand: {s:?}"));
+    };
+    Ok(Operand { mode, value })
+}
+
+/// A sum of numbers and labels like `loop+2` or `end - start - 1`, added up left to right
+fn evaluate(s: &str, labels: &HashMap<&str, i64>) -> Result<i64, String> {
+    let s = s.trim();
+    let (mut negative, mut rest) = match s.split_at_checked(1) {
+        Some(("-", rest)) => (true, rest),
+        Some(("+", rest)) => (false, rest),
+        _ => (false, s),
+    };
+    let mut total = 0_i64;
+    loop {
+        let end = rest.find(['+', '-']).unwrap_or(rest.len());
+        let value = evaluate_term(rest[..end].trim(), labels)?;
+        total = if negative {
+            total.checked_sub(value)
+        } else {
+            total.checked_add(value)
+        }
+        .ok_or_else(|| format!("Overflow in {s:?}"))?;
+        if end == rest.len() {
+            return Ok(total);
+        }
+        negative = rest[end..].starts_with('-');
+        rest = &rest[end + 1..];
+    }
+}
+
+/// A number or a label
+fn evaluate_term(s: &str, labels: &HashMap<&str, i64>) -> Result<i64, String> {
+    if s.is_empty() {
+        return Err("Missing term".to_string());
+    }
+    if let Ok(number) = s.parse() {
+        return Ok(number);
+    }
+    labels
+        .get(s)
+        .copied()
+        .ok_or_else(|| format!("Unknown label: {s:?}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intcode::{disassemble, Machine};
+
+    #[test]
+    fn test_round_trip() {
+        let programs = [
+            vec![1102, 34915192, 34915192, 7, 4, 7, 99, 0],
+            vec![
+                109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
+            ],
+            vec![
+                3, 21, 1008, 21, 8, 20, 1005, 20, 22, 107, 8, 21, 20, 1006, 20, 31, 1106, 0, 36,
+                98, 0, 0, 1002, 21, 125, 20, 4, 20, 1105, 1, 46, 104, 999, 1105, 1, 46, 1101, 1000,
+                1, 20, 4, 20, 1105, 1, 46, 98, 99,
+            ],
+        ];
+        for program in programs {
+            assert_eq!(assemble(&disassemble(&program).to_string()), Ok(program));
+        }
+    }
+
+    #[test]
+    fn test_labels() {
+        let source = "
+                    in [counter]
+            loop:   out [counter]      ; counts down to 1
+                    add [counter], #-1, [counter]
+                    jt [counter], #loop
+                    hlt
+            counter: data 0
+        ";
+        let program = assemble(source).unwrap();
+        assert_eq!(
+            program,
+            [3, 12, 4, 12, 1001, 12, -1, 12, 1005, 12, 2, 99, 0]
+        );
+
+        let mut machine = Machine::new(program);
+        machine.inputs.push_back(3);
+        machine.run().unwrap();
+        assert_eq!(machine.outputs, [3, 2, 1]);
+    }
+
+    #[test]
+    fn test_operands() {
+        let source = "
+            start: arb #end
+            out [rb-1]
+            out [rb]
+            out [rb + 1]
+            hlt
+            data start+1, -5, 7
+            end: 12: data end - 2
+        ";
+        assert_eq!(
+            assemble(source),
+            Ok(vec![109, 12, 204, -1, 204, 0, 204, 1, 99, 1, -5, 7, 10])
+        );
+    }
+
+    #[test]
+    fn test_expressions() {
+        let source = "
+            a: data 5 - 2 - 1, e-c+b, a+1-2, -c - 1
+            b: data +e
+            c: data 0
+            e: data 0
+        ";
+        // b is 4, c is 5 and e is 6
+        assert_eq!(assemble(source), Ok(vec![2, 5, -1, -6, 6, 0, 0]));
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = |source| assemble(source).unwrap_err();
+        assert_eq!(error("hlt\nnop"), "Line 2: Unknown mnemonic: nop");
+        assert_eq!(error("out #1, #2"), "Line 1: out takes 1 operands, not 2");
+        assert_eq!(
+            error("jt #1, #nowhere"),
+            "Line 1: Unknown label: \"nowhere\""
+        );
+        assert_eq!(error("a: hlt\na: hlt"), "Line 2: Duplicate label: a");
+        assert_eq!(error("hlt\n3: hlt"), "Line 2: Expected address 3, at 1");
+        assert_eq!(error("out 5"), "Line 1: Invalid operand: \"5\"");
+        assert_eq!(error("data 5 - - 2"), "Line 1: Missing term");
+    }
+}
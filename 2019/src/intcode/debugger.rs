@@ -0,0 +1,452 @@
+use super::{InstructionType, IntcodeError, Line, Machine, MachineState};
+use itertools::Itertools;
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+
+/// How many instructions the trace keeps unless told otherwise
+const TRACE_CAPACITY: usize = 64;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Breakpoint {
+    /// Before running the instruction at an address
+    Address(usize),
+    /// Before running any instruction of a type
+    Opcode(InstructionType),
+}
+
+/// Why the debugger handed control back
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Stop {
+    Breakpoint(Breakpoint),
+    /// A watched address was written to, even if the value didn't change
+    Watchpoint {
+        address: usize,
+        value: i64,
+    },
+    /// The machine is waiting for input or has halted
+    Machine(MachineState),
+    /// The next instruction can't run
+    Fault(IntcodeError),
+}
+
+impl fmt::Display for Stop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stop::Breakpoint(Breakpoint::Address(address)) => write!(f, "Breakpoint at {address}"),
+            Stop::Breakpoint(Breakpoint::Opcode(r#type)) => {
+                write!(f, "Breakpoint on {}", r#type.mnemonic())
+            }
+            Stop::Watchpoint { address, value } => write!(f, "Wrote {value} to [{address}]"),
+            Stop::Machine(MachineState::NeedMoreInput) => write!(f, "Waiting for input"),
+            Stop::Machine(MachineState::HitStopInstruction) => write!(f, "Halted"),
+            Stop::Machine(state) => write!(f, "{state:?}"),
+            Stop::Fault(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+/// An instruction that ran
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Trace {
+    pub line: Line,
+    /// The relative base the instruction ran with
+    pub relative_base: i64,
+    pub write: Option<(usize, i64)>,
+}
+
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}  rb={}", self.line, self.relative_base)?;
+        if let Some((address, value)) = self.write {
+            write!(f, "  [{address}] <- {value}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs a [`Machine`] a bit at a time, stopping at breakpoints and watched writes and keeping a
+/// trace of the last instructions it ran
+#[derive(Clone)]
+pub struct Debugger {
+    machine: Machine,
+    breakpoints: HashSet<Breakpoint>,
+    watchpoints: HashSet<usize>,
+    trace: VecDeque<Trace>,
+    trace_capacity: usize,
+}
+
+impl Debugger {
+    pub fn new(machine: Machine) -> Self {
+        Self {
+            machine,
+            breakpoints: HashSet::new(),
+            watchpoints: HashSet::new(),
+            trace: VecDeque::new(),
+            trace_capacity: TRACE_CAPACITY,
+        }
+    }
+
+    /// Keeps the last `capacity` instructions in the trace instead
+    pub fn with_trace_capacity(mut self, capacity: usize) -> Self {
+        self.trace_capacity = capacity;
+        self.trace.truncate(capacity);
+        self
+    }
+
+    pub fn machine(&self) -> &Machine {
+        &self.machine
+    }
+
+    /// For adding inputs or patching memory
+    pub fn machine_mut(&mut self) -> &mut Machine {
+        &mut self.machine
+    }
+
+    pub fn into_machine(self) -> Machine {
+        self.machine
+    }
+
+    /// Returns whether the breakpoint is new
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) -> bool {
+        self.breakpoints.insert(breakpoint)
+    }
+
+    /// Returns whether there was such a breakpoint
+    pub fn remove_breakpoint(&mut self, breakpoint: Breakpoint) -> bool {
+        self.breakpoints.remove(&breakpoint)
+    }
+
+    /// Returns whether the address wasn't watched already
+    pub fn watch(&mut self, address: usize) -> bool {
+        self.watchpoints.insert(address)
+    }
+
+    /// Returns whether the address was watched
+    pub fn unwatch(&mut self, address: usize) -> bool {
+        self.watchpoints.remove(&address)
+    }
+
+    /// The last instructions that ran, oldest first
+    pub fn trace(&self) -> impl Iterator<Item = &Trace> {
+        self.trace.iter()
+    }
+
+    /// Runs one instruction, returning why it should stop there if it should
+    pub fn step(&mut self) -> Option<Stop> {
+        let line = self.machine.current_instruction();
+        let relative_base = self.machine.relative_base();
+        let state = match self.machine.step() {
+            Ok(state) => state,
+            Err(error) => return Some(Stop::Fault(error)),
+        };
+        let write = self.machine.last_write();
+
+        if let Some(line) = line.filter(|_| state != MachineState::NeedMoreInput) {
+            if self.trace.len() == self.trace_capacity {
+                self.trace.pop_front();
+            }
+            if self.trace_capacity > 0 {
+                self.trace.push_back(Trace {
+                    line,
+                    relative_base,
+                    write,
+                });
+            }
+        }
+
+        match state {
+            MachineState::Running => write
+                .filter(|(address, _)| self.watchpoints.contains(address))
+                .map(|(address, value)| Stop::Watchpoint { address, value }),
+            state => Some(Stop::Machine(state)),
+        }
+    }
+
+    /// Runs until something makes it stop. A breakpoint on the instruction it starts on only
+    /// counts if the machine hasn't started yet, so that it can carry on from a breakpoint.
+    pub fn resume(&mut self) -> Stop {
+        if self.machine.state == MachineState::NotStarted {
+            if let Some(breakpoint) = self.breakpoint() {
+                return Stop::Breakpoint(breakpoint);
+            }
+        }
+        loop {
+            if let Some(stop) = self.step() {
+                return stop;
+            }
+            if let Some(breakpoint) = self.breakpoint() {
+                return Stop::Breakpoint(breakpoint);
+            }
+        }
+    }
+
+    /// The breakpoint on the next instruction, if there is one
+    fn breakpoint(&self) -> Option<Breakpoint> {
+        let cursor = self.machine.cursor();
+        let address = Breakpoint::Address(cursor);
+        let opcode = InstructionType::try_parse(self.machine.read(cursor)).map(Breakpoint::Opcode);
+        [Some(address), opcode]
+            .into_iter()
+            .flatten()
+            .find(|breakpoint| self.breakpoints.contains(breakpoint))
+    }
+
+    /// The commands [`Debugger::command`] understands
+    pub const HELP: &'static str = "\
+s [n]            step n instructions, 1 by default, which is also what an empty line does
+c                continue until a breakpoint, a watched write, input is needed or it halts
+b [addr|opcode]  break at an address, the current one by default, or on a mnemonic like out
+d [addr|opcode]  delete a breakpoint
+w addr           stop after writes to an address
+u addr           stop watching an address
+i value...       add inputs
+r                show the cursor, relative base, state and pending inputs
+m addr [n]       show n words of memory, 8 by default and at most the program's length
+l [addr] [n]     list n instructions from an address, the cursor by default
+t                show the trace of the last instructions run
+o                show the outputs
+h                show this help
+q                quit";
+
+    /// Carries out a command typed into the REPL, returning what to show for it. See
+    /// [`Debugger::HELP`].
+    pub fn command(&mut self, command: &str) -> Result<String, String> {
+        let mut words = command.split_whitespace();
+        let name = words.next().unwrap_or("s");
+        let arguments = words.collect_vec();
+        let number = |index: usize, default: Option<i64>| -> Result<i64, String> {
+            match arguments.get(index) {
+                Some(argument) => argument
+                    .parse()
+                    .map_err(|_| format!("Not a number: {argument}")),
+                None => default.ok_or_else(|| format!("{name} needs an argument")),
+            }
+        };
+        let address = |index| {
+            number(index, None).and_then(|address| {
+                usize::try_from(address).map_err(|_| format!("Not an address: {address}"))
+            })
+        };
+        // memory and listings are never longer than the program, so neither is a count
+        let count = |index| {
+            let limit = self.machine.program.len();
+            number(index, Some(8)).and_then(|count| {
+                usize::try_from(count)
+                    .ok()
+                    .filter(|&count| count <= limit)
+                    .ok_or_else(|| format!("Not a count: {count}, the most is {limit}"))
+            })
+        };
+        let breakpoint = || match arguments.first() {
+            Some(argument) => match InstructionType::from_mnemonic(argument) {
+                Some(r#type) => Ok(Breakpoint::Opcode(r#type)),
+                None => address(0).map(Breakpoint::Address),
+            },
+            None => Ok(Breakpoint::Address(self.machine.cursor())),
+        };
+
+        Ok(match name {
+            "s" | "step" => {
+                let mut stop = None;
+                for _ in 0..number(0, Some(1))? {
+                    stop = self.step();
+                    if stop.is_some() {
+                        break;
+                    }
+                }
+                self.show_stop(stop)
+            }
+            "c" | "continue" => {
+                let stop = self.resume();
+                self.show_stop(Some(stop))
+            }
+            "b" | "break" => {
+                let breakpoint = breakpoint()?;
+                self.add_breakpoint(breakpoint);
+                format!("Added {breakpoint:?}")
+            }
+            "d" | "delete" => {
+                let breakpoint = breakpoint()?;
+                match self.remove_breakpoint(breakpoint) {
+                    true => format!("Deleted {breakpoint:?}"),
+                    false => return Err(format!("No {breakpoint:?}")),
+                }
+            }
+            "w" | "watch" => {
+                let address = address(0)?;
+                self.watch(address);
+                format!("Watching [{address}]")
+            }
+            "u" | "unwatch" => {
+                let address = address(0)?;
+                match self.unwatch(address) {
+                    true => format!("Stopped watching [{address}]"),
+                    false => return Err(format!("Not watching [{address}]")),
+                }
+            }
+            "i" | "input" => {
+                let values = (0..arguments.len())
+                    .map(|index| number(index, None))
+                    .collect::<Result<Vec<_>, _>>()?;
+                self.machine.inputs.extend(values);
+                format!("Pending inputs: {:?}", self.machine.inputs)
+            }
+            "r" | "registers" => format!(
+                "cursor={} rb={} state={:?} inputs={:?}",
+                self.machine.cursor(),
+                self.machine.relative_base(),
+                self.machine.state,
+                self.machine.inputs
+            ),
+            "m" | "memory" => {
+                let start = address(0)?;
+                let values = (start..).take(count(1)?).map(|a| self.machine.read(a));
+                format!("{start:>5}: {}", values.format(", "))
+            }
+            "l" | "list" => {
+                let start = match arguments.first() {
+                    Some(_) => address(0)?,
+                    None => self.machine.cursor(),
+                };
+                self.list(start, count(1)?)
+            }
+            "t" | "trace" => self.trace.iter().join("\n"),
+            "o" | "outputs" => format!("{:?}", self.machine.outputs),
+            "h" | "help" => Self::HELP.to_string(),
+            _ => return Err(format!("Unknown command: {name}, try h for help")),
+        })
+    }
+
+    fn show_stop(&self, stop: Option<Stop>) -> String {
+        let next = self.list(self.machine.cursor(), 1);
+        match stop {
+            Some(stop) => format!("{stop}\n{next}"),
+            None => next,
+        }
+    }
+
+    /// Decodes `count` lines from `start`, showing words that aren't instructions as data
+    fn list(&self, start: usize, count: usize) -> String {
+        let program = &self.machine.program;
+        let mut address = start;
+        (0..count)
+            .map_while(|_| {
+                let line = Line::decode(program, address).or_else(|| {
+                    let value = *program.get(address)?;
+                    Some(Line::Data {
+                        address,
+                        values: vec![value],
+                    })
+                })?;
+                address += line.words().len();
+                Some(line)
+            })
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intcode::assemble;
+
+    fn countdown() -> Debugger {
+        let program = assemble(
+            "
+                    in [counter]
+            loop:   out [counter]
+                    add [counter], #-1, [counter]
+                    jt [counter], #loop
+                    hlt
+            counter: data 0
+            ",
+        )
+        .unwrap();
+        Debugger::new(Machine::new(program))
+    }
+
+    #[test]
+    fn test_stops() {
+        let mut debugger = countdown();
+        assert_eq!(
+            debugger.resume(),
+            Stop::Machine(MachineState::NeedMoreInput)
+        );
+        debugger.machine_mut().inputs.push_back(3);
+
+        debugger.add_breakpoint(Breakpoint::Address(2));
+        assert_eq!(debugger.resume(), Stop::Breakpoint(Breakpoint::Address(2)));
+        assert_eq!(debugger.machine().read(12), 3);
+        assert_eq!(debugger.resume(), Stop::Breakpoint(Breakpoint::Address(2)));
+        assert_eq!(debugger.machine().outputs, [3]);
+        assert!(debugger.remove_breakpoint(Breakpoint::Address(2)));
+
+        assert!(debugger.watch(12));
+        assert_eq!(
+            debugger.resume(),
+            Stop::Watchpoint {
+                address: 12,
+                value: 1
+            }
+        );
+        assert_eq!(debugger.machine().cursor(), 8);
+        assert!(debugger.unwatch(12));
+
+        debugger.add_breakpoint(Breakpoint::Opcode(InstructionType::Stop));
+        assert_eq!(
+            debugger.resume(),
+            Stop::Breakpoint(Breakpoint::Opcode(InstructionType::Stop))
+        );
+        assert_eq!(
+            debugger.step(),
+            Some(Stop::Machine(MachineState::HitStopInstruction))
+        );
+        assert_eq!(debugger.into_machine().outputs, [3, 2, 1]);
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut debugger = countdown().with_trace_capacity(3);
+        debugger.machine_mut().inputs.push_back(2);
+        debugger.resume();
+        let trace = debugger.trace().collect_vec();
+        assert_eq!(
+            trace.iter().map(|trace| trace.line.address()).collect_vec(),
+            [4, 8, 11]
+        );
+        assert_eq!(trace[0].write, Some((12, 0)));
+        assert_eq!(trace[1].write, None);
+    }
+
+    #[test]
+    fn test_commands() {
+        let mut debugger = countdown();
+        assert_eq!(
+            debugger.command("c"),
+            Ok("Waiting for input\n    0: in [12]                          ; 3,12".to_string())
+        );
+        assert!(debugger.command("i 2 1").is_ok());
+        assert!(debugger.command("b out").is_ok());
+        assert_eq!(
+            debugger.command("c").unwrap().lines().next(),
+            Some("Breakpoint on out")
+        );
+        assert_eq!(
+            debugger.command("r"),
+            Ok("cursor=2 rb=0 state=Running inputs=[1]".to_string())
+        );
+        assert_eq!(
+            debugger.command("m 10 4"),
+            Ok("   10: 2, 99, 2, 0".to_string())
+        );
+        assert_eq!(debugger.command("l 11 5").unwrap().lines().count(), 2);
+        assert!(debugger.command("m 0 -1").is_err());
+        assert!(debugger.command("m 0 14").is_err());
+        assert!(debugger.command("l 0 -1").is_err());
+        assert!(debugger.command("s 3").is_ok());
+        assert_eq!(debugger.command("o"), Ok("[2]".to_string()));
+        assert!(debugger.command("d 2").is_err());
+        assert!(debugger.command("w x").is_err());
+        assert!(debugger.command("jump").is_err());
+    }
+}
@@ -0,0 +1,326 @@
+use super::{InstructionType, OperandType};
+use itertools::Itertools;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+
+/// Words of data are listed this many to a line
+const DATA_PER_LINE: usize = 8;
+
+/// An instruction's parameter, written `[addr]` in position mode, `#imm` in immediate mode and
+/// `[rb+n]` in relative mode
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Operand {
+    pub mode: OperandType,
+    pub value: i64,
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.mode {
+            OperandType::Position => write!(f, "[{}]", self.value),
+            OperandType::Immediate => write!(f, "#{}", self.value),
+            OperandType::Relative if self.value < 0 => write!(f, "[rb{}]", self.value),
+            OperandType::Relative => write!(f, "[rb+{}]", self.value),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Line {
+    Instruction {
+        address: usize,
+        r#type: InstructionType,
+        operands: Vec<Operand>,
+    },
+    /// Words that are never run, as far as the disassembler can tell
+    Data { address: usize, values: Vec<i64> },
+}
+
+impl Line {
+    /// The instruction at `address`, if it's one that can run without crashing
+    pub fn decode(program: &[i64], address: usize) -> Option<Line> {
+        let (r#type, operands) = decode(program, address)?;
+        Some(Line::Instruction {
+            address,
+            r#type,
+            operands,
+        })
+    }
+
+    pub fn address(&self) -> usize {
+        match self {
+            Line::Instruction { address, .. } | Line::Data { address, .. } => *address,
+        }
+    }
+
+    fn size(&self) -> usize {
+        match self {
+            Line::Instruction { operands, .. } => 1 + operands.len(),
+            Line::Data { values, .. } => values.len(),
+        }
+    }
+
+    /// The words of the program this line stands for
+    pub fn words(&self) -> Vec<i64> {
+        match self {
+            Line::Instruction {
+                r#type, operands, ..
+            } => {
+                let modes = operands
+                    .iter()
+                    .rev()
+                    .fold(0, |modes, operand| modes * 10 + operand.mode.digit());
+                let opcode = modes * 100 + r#type.opcode();
+                [opcode]
+                    .into_iter()
+                    .chain(operands.iter().map(|operand| operand.value))
+                    .collect()
+            }
+            Line::Data { values, .. } => values.clone(),
+        }
+    }
+}
+
+/// Lines look like `   12: add [rb+1], #3, [100]` with the raw words in a comment after
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Line::Instruction {
+                address,
+                r#type,
+                operands,
+            } => {
+                let source = format!("{} {}", r#type.mnemonic(), operands.iter().join(", "));
+                let words = self.words().iter().join(",");
+                write!(f, "{address:>5}: {:<32} ; {words}", source.trim_end())
+            }
+            Line::Data { address, values } => {
+                write!(f, "{address:>5}: data {}", values.iter().join(", "))
+            }
+        }
+    }
+}
+
+/// A program split into instructions and data, in address order
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Listing {
+    lines: Vec<Line>,
+}
+
+impl Listing {
+    pub fn lines(&self) -> &[Line] {
+        &self.lines
+    }
+
+    /// The line covering `address`
+    pub fn line_at(&self, address: usize) -> Option<&Line> {
+        let index = self
+            .lines
+            .partition_point(|line| line.address() <= address)
+            .checked_sub(1)?;
+        let line = &self.lines[index];
+        (address < line.address() + line.size()).then_some(line)
+    }
+}
+
+impl fmt::Display for Listing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for line in &self.lines {
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Disassembles everything that can be reached from address 0, see [`disassemble_from`]
+pub fn disassemble(program: &[i64]) -> Listing {
+    disassemble_from(program, [0])
+}
+
+/// Follows the program from each of the entry points, and lists everything it never runs into as
+/// data.
+///
+/// Only jumps to immediate addresses can be followed. A call is usually an unconditional jump
+/// with the return address stored beforehand, so the code after an unconditional jump is
+/// followed too if its address turns up as an immediate operand somewhere else.
+pub fn disassemble_from(program: &[i64], entry_points: impl IntoIterator<Item = usize>) -> Listing {
+    let mut code = BTreeMap::new();
+    let mut pending = entry_points.into_iter().collect_vec();
+    let mut visited = HashSet::new();
+    let mut immediates = HashSet::new();
+    let mut after_jumps = vec![];
+
+    loop {
+        while let Some(address) = pending.pop() {
+            if !visited.insert(address) {
+                continue;
+            }
+            let Some((r#type, operands)) = decode(program, address) else {
+                continue;
+            };
+            let next = address + 1 + operands.len();
+            immediates.extend(
+                operands
+                    .iter()
+                    .filter(|operand| operand.mode == OperandType::Immediate)
+                    .map(|operand| operand.value),
+            );
+
+            let falls_through = match (r#type, operands.first()) {
+                (InstructionType::Stop, _) => false,
+                (InstructionType::JumpIfTrue, Some(condition)) => {
+                    !is_immediate(condition, |x| x != 0)
+                }
+                (InstructionType::JumpIfFalse, Some(condition)) => {
+                    !is_immediate(condition, |x| x == 0)
+                }
+                _ => true,
+            };
+            if matches!(
+                r#type,
+                InstructionType::JumpIfTrue | InstructionType::JumpIfFalse
+            ) && operands[1].mode == OperandType::Immediate
+            {
+                pending.extend(usize::try_from(operands[1].value));
+            }
+            if falls_through {
+                pending.push(next);
+            } else {
+                after_jumps.push(next);
+            }
+            code.insert(address, (r#type, operands));
+        }
+
+        pending.extend(after_jumps.iter().copied().filter(|address| {
+            !visited.contains(address) && immediates.contains(&(*address as i64))
+        }));
+        if pending.is_empty() {
+            break;
+        }
+    }
+
+    let mut lines = vec![];
+    let mut address = 0;
+    while address < program.len() {
+        if let Some((r#type, operands)) = code.remove(&address) {
+            let size = 1 + operands.len();
+            lines.push(Line::Instruction {
+                address,
+                r#type,
+                operands,
+            });
+            address += size;
+            continue;
+        }
+        match lines.last_mut() {
+            Some(Line::Data {
+                address: start,
+                values,
+            }) if *start + values.len() == address && values.len() < DATA_PER_LINE => {
+                values.push(program[address]);
+            }
+            _ => lines.push(Line::Data {
+                address,
+                values: vec![program[address]],
+            }),
+        }
+        address += 1;
+    }
+    Listing { lines }
+}
+
+fn is_immediate(operand: &Operand, f: impl Fn(i64) -> bool) -> bool {
+    operand.mode == OperandType::Immediate && f(operand.value)
+}
+
+/// The instruction at `address`, if it's one that can run without crashing
+fn decode(program: &[i64], address: usize) -> Option<(InstructionType, Vec<Operand>)> {
+    let word = *program.get(address)?;
+    if word < 0 {
+        return None;
+    }
+    let r#type = InstructionType::try_parse(word)?;
+    let mut modes = word / 100;
+    let operands = (1..=r#type.operand_count())
+        .map(|offset| {
+            let mode = OperandType::try_parse(modes % 10)?;
+            modes /= 10;
+            let value = *program.get(address + offset)?;
+            Some(Operand { mode, value })
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let writes_immediate =
+        written_operand(r#type).is_some_and(|index| operands[index].mode == OperandType::Immediate);
+    (modes == 0 && !writes_immediate).then_some((r#type, operands))
+}
+
+fn written_operand(r#type: InstructionType) -> Option<usize> {
+    use InstructionType::*;
+    match r#type {
+        Add | Multiply | LessThan | Equals => Some(2),
+        Input => Some(0),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operands() {
+        let operand = |mode, value| Operand { mode, value }.to_string();
+        assert_eq!(operand(OperandType::Position, 7), "[7]");
+        assert_eq!(operand(OperandType::Immediate, -3), "#-3");
+        assert_eq!(operand(OperandType::Relative, 2), "[rb+2]");
+        assert_eq!(operand(OperandType::Relative, -1), "[rb-1]");
+    }
+
+    #[test]
+    fn test_data() {
+        let program = vec![1102, 34915192, 34915192, 7, 4, 7, 99, 0];
+        let listing = disassemble(&program);
+        assert_eq!(
+            listing.to_string(),
+            "    0: mul #34915192, #34915192, [7]    ; 1102,34915192,34915192,7\n\
+             \x20   4: out [7]                          ; 4,7\n\
+             \x20   6: hlt                              ; 99\n\
+             \x20   7: data 0\n"
+        );
+        assert_eq!(listing.line_at(5).map(Line::address), Some(4));
+        assert_eq!(listing.line_at(8), None);
+        let words = listing.lines().iter().flat_map(Line::words).collect_vec();
+        assert_eq!(words, program);
+    }
+
+    #[test]
+    fn test_jumps() {
+        // outputs 1 if the input is 8, jumping over the other output
+        let program = vec![
+            3, 3, 1108, -1, 8, 3, 1005, 3, 13, 104, 0, 99, 42, 104, 1, 99,
+        ];
+        let listing = disassemble(&program);
+        let data = listing
+            .lines()
+            .iter()
+            .filter(|line| matches!(line, Line::Data { .. }))
+            .map(Line::address)
+            .collect_vec();
+        assert_eq!(data, [12]);
+        assert_eq!(
+            listing.line_at(6).unwrap().to_string(),
+            "    6: jt [3], #13                      ; 1005,3,13"
+        );
+
+        // the code after a call is reached by its return address, stored before the jump
+        let program = vec![21101, 7, 0, 0, 1105, 1, 9, 99, 0, 2106, 0, 0];
+        let listing = disassemble(&program);
+        assert_eq!(
+            listing.lines().iter().map(Line::address).collect_vec(),
+            [0, 4, 7, 8, 9]
+        );
+        assert!(matches!(listing.lines()[3], Line::Data { .. }));
+        assert!(matches!(listing.lines()[4], Line::Instruction { .. }));
+    }
+}
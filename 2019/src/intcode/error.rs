@@ -0,0 +1,202 @@
+use std::fmt;
+
+/// Why an instruction couldn't run. Each carries the address of the faulting instruction and
+/// the instruction itself, opcode and modes together as in memory.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum IntcodeError {
+    InvalidOpcode {
+        address: usize,
+        instruction: i64,
+    },
+    /// A parameter mode other than 0, 1 or 2
+    InvalidMode {
+        address: usize,
+        instruction: i64,
+        parameter: usize,
+    },
+    /// A parameter that's written to is in immediate mode
+    WriteToImmediate {
+        address: usize,
+        instruction: i64,
+        parameter: usize,
+    },
+    /// A parameter points before the start of memory
+    NegativeAddress {
+        address: usize,
+        instruction: i64,
+        target: i64,
+    },
+    /// A jump to before the start or past the end of memory
+    JumpOutOfBounds {
+        address: usize,
+        instruction: i64,
+        target: i64,
+    },
+    /// An address past [`MEMORY_LIMIT`](super::MEMORY_LIMIT) is written to
+    OutOfMemory {
+        address: usize,
+        instruction: i64,
+        target: usize,
+    },
+    /// An addition or multiplication, or the relative base, outgrows an `i64`
+    Overflow {
+        address: usize,
+        instruction: i64,
+    },
+}
+
+impl fmt::Display for IntcodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntcodeError::InvalidOpcode {
+                address,
+                instruction,
+            } => write!(f, "Invalid opcode in {instruction} at {address}"),
+            IntcodeError::InvalidMode {
+                address,
+                instruction,
+                parameter,
+            } => write!(
+                f,
+                "Invalid mode for parameter {parameter} of {instruction} at {address}"
+            ),
+            IntcodeError::WriteToImmediate {
+                address,
+                instruction,
+                parameter,
+            } => write!(
+                f,
+                "Write to immediate parameter {parameter} of {instruction} at {address}"
+            ),
+            IntcodeError::NegativeAddress {
+                address,
+                instruction,
+                target,
+            } => write!(f, "Negative address {target} in {instruction} at {address}"),
+            IntcodeError::JumpOutOfBounds {
+                address,
+                instruction,
+                target,
+            } => write!(
+                f,
+                "Jump to {target} out of bounds in {instruction} at {address}"
+            ),
+            IntcodeError::OutOfMemory {
+                address,
+                instruction,
+                target,
+            } => write!(
+                f,
+                "Write to {target} out of memory in {instruction} at {address}"
+            ),
+            IntcodeError::Overflow {
+                address,
+                instruction,
+            } => write!(f, "Overflow in {instruction} at {address}"),
+        }
+    }
+}
+
+impl std::error::Error for IntcodeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intcode::{Machine, MachineState};
+
+    fn run(program: Vec<i64>) -> IntcodeError {
+        Machine::new(program).run().unwrap_err()
+    }
+
+    #[test]
+    fn test_errors() {
+        use IntcodeError::*;
+        assert_eq!(
+            run(vec![42]),
+            InvalidOpcode {
+                address: 0,
+                instruction: 42
+            }
+        );
+        // running off the end reads the zeroes past it
+        assert_eq!(
+            run(vec![104, 1]),
+            InvalidOpcode {
+                address: 2,
+                instruction: 0
+            }
+        );
+        assert_eq!(
+            run(vec![304, 0]),
+            InvalidMode {
+                address: 0,
+                instruction: 304,
+                parameter: 0
+            }
+        );
+        assert_eq!(
+            run(vec![1101, 1, 1, 6, 11101, 1, 1, 0]),
+            WriteToImmediate {
+                address: 4,
+                instruction: 11101,
+                parameter: 2
+            }
+        );
+        assert_eq!(
+            run(vec![204, -3]),
+            NegativeAddress {
+                address: 0,
+                instruction: 204,
+                target: -3
+            }
+        );
+        assert_eq!(
+            run(vec![1106, 0, -1]),
+            JumpOutOfBounds {
+                address: 0,
+                instruction: 1106,
+                target: -1
+            }
+        );
+        assert_eq!(
+            run(vec![1101, 1, 1, 1 << 40]),
+            OutOfMemory {
+                address: 0,
+                instruction: 1101,
+                target: 1 << 40
+            }
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        use IntcodeError::Overflow;
+        let overflow = |address, instruction| Overflow {
+            address,
+            instruction,
+        };
+        assert_eq!(run(vec![1101, i64::MAX, 1, 0, 99]), overflow(0, 1101));
+        assert_eq!(run(vec![1102, i64::MIN, -1, 0, 99]), overflow(0, 1102));
+        assert_eq!(run(vec![109, i64::MAX, 204, 1, 99]), overflow(2, 204));
+        assert_eq!(run(vec![109, i64::MAX, 109, 1, 99]), overflow(2, 109));
+
+        // the faulting step leaves the state and last write as they were
+        let mut machine = Machine::new(vec![1101, 1, 1, 9, 1101, i64::MAX, 1, 9, 99, 0]);
+        assert_eq!(machine.step(), Ok(MachineState::Running));
+        machine.state = MachineState::NeedMoreInput;
+        assert_eq!(machine.step(), Err(overflow(4, 1101)));
+        assert_eq!(machine.state, MachineState::NeedMoreInput);
+        assert_eq!(machine.last_write(), Some((9, 2)));
+        assert_eq!(machine.read(9), 2);
+    }
+
+    #[test]
+    fn test_fault_leaves_machine() {
+        let mut machine = Machine::new(vec![104, 7, 1105, 1, 100]);
+        let error = machine.run().unwrap_err();
+        assert_eq!(error.to_string(), "Jump to 100 out of bounds in 1105 at 2");
+        assert_eq!(machine.cursor(), 2);
+        assert_eq!(machine.outputs, [7]);
+        assert_eq!(machine.step(), Err(error));
+    }
+}
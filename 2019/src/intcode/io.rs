@@ -0,0 +1,151 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+use std::sync::mpsc::{Receiver, Sender};
+
+/// Where a [`Machine`](super::Machine)'s `in` instructions read from
+pub trait InputSource {
+    /// The next input, or `None` to make the machine wait for more
+    fn read(&mut self) -> Option<i64>;
+}
+
+/// Where a [`Machine`](super::Machine)'s `out` instructions write to
+pub trait OutputSink {
+    fn write(&mut self, value: i64);
+}
+
+impl InputSource for VecDeque<i64> {
+    fn read(&mut self) -> Option<i64> {
+        self.pop_front()
+    }
+}
+
+impl OutputSink for VecDeque<i64> {
+    fn write(&mut self, value: i64) {
+        self.push_back(value);
+    }
+}
+
+impl OutputSink for Vec<i64> {
+    fn write(&mut self, value: i64) {
+        self.push(value);
+    }
+}
+
+/// Never blocks, so a machine on another thread waits for input like any other
+impl InputSource for Receiver<i64> {
+    fn read(&mut self) -> Option<i64> {
+        self.try_recv().ok()
+    }
+}
+
+/// Outputs nobody is listening for any more are dropped
+impl OutputSink for Sender<i64> {
+    fn write(&mut self, value: i64) {
+        let _ = self.send(value);
+    }
+}
+
+/// A queue shared between its clones, for wiring one machine's output to another's input on the
+/// same thread
+#[derive(Clone, Debug, Default)]
+pub struct Channel(Rc<RefCell<VecDeque<i64>>>);
+
+impl Channel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&self, value: i64) {
+        self.0.borrow_mut().push_back(value);
+    }
+
+    pub fn pop(&self) -> Option<i64> {
+        self.0.borrow_mut().pop_front()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.borrow().is_empty()
+    }
+}
+
+impl InputSource for Channel {
+    fn read(&mut self) -> Option<i64> {
+        self.pop()
+    }
+}
+
+impl OutputSink for Channel {
+    fn write(&mut self, value: i64) {
+        self.push(value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intcode::{assemble, Machine, MachineState};
+    use std::sync::mpsc;
+    use std::thread;
+
+    /// Adds up pairs of inputs until it reads a 0
+    fn adder() -> Vec<i64> {
+        assemble(
+            "
+            loop:   in [a]
+                    jf [a], #end
+                    in [b]
+                    add [a], [b], [a]
+                    out [a]
+                    jt #1, #loop
+            end:    hlt
+            a:      data 0
+            b:      data 0
+            ",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_channels() {
+        let (input, link, output) = (Channel::new(), Channel::new(), Channel::new());
+        let mut first = Machine::with_io(adder(), input.clone(), link.clone());
+        let mut second = Machine::with_io(adder(), link.clone(), output.clone());
+
+        for value in [1, 2, 3, 4] {
+            input.push(value);
+        }
+        assert_eq!(first.run(), Ok(MachineState::NeedMoreInput));
+        assert_eq!(link.len(), 2);
+        assert_eq!(second.run(), Ok(MachineState::NeedMoreInput));
+        assert!(link.is_empty());
+        assert_eq!(output.pop(), Some(10));
+
+        input.push(0);
+        link.push(0);
+        assert_eq!(first.run(), Ok(MachineState::HitStopInstruction));
+        assert_eq!(second.run(), Ok(MachineState::HitStopInstruction));
+    }
+
+    #[test]
+    fn test_threads() {
+        let (input, machine_input) = mpsc::channel();
+        let (machine_output, output) = mpsc::channel();
+        let handle = thread::spawn(move || {
+            let mut machine = Machine::with_io(adder(), machine_input, machine_output);
+            while machine.run() == Ok(MachineState::NeedMoreInput) {
+                thread::yield_now();
+            }
+        });
+
+        for value in [5, 6, 7, 8, 0] {
+            input.send(value).unwrap();
+        }
+        handle.join().unwrap();
+        assert_eq!(output.iter().collect::<Vec<_>>(), [11, 15]);
+    }
+}
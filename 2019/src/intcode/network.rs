@@ -0,0 +1,295 @@
+use super::{Channel, IntcodeError, Machine, MachineState};
+use std::collections::VecDeque;
+
+/// Rounds in a row without any machine getting or sending anything before the network counts as
+/// idle, as a machine fed the idle input might take a round to settle back into waiting
+const QUIET_ROUNDS: usize = 2;
+
+/// Where the machines' outputs go
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Routing {
+    /// Each machine's outputs are the next machine's inputs, and the last one's go round to
+    /// the first, as in a feedback loop
+    Ring,
+    /// Outputs come in packets of a destination address followed by `size` values. Machines
+    /// are addressed by their index, and packets for any other address come out of
+    /// [`Network::run`].
+    Packets { size: usize },
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Packet {
+    pub destination: i64,
+    pub values: Vec<i64>,
+}
+
+/// Why [`Network::run`] handed control back
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Event {
+    /// A packet for an address outside the network
+    Packet(Packet),
+    /// Nothing has been sent for a while, or every machine that hasn't halted is stuck
+    /// waiting for input
+    Idle,
+    /// Every machine has halted
+    Halted,
+}
+
+/// Machines that run a round at a time, each until it waits for input, passing their outputs
+/// on to each other through [`Channel`]s
+pub struct Network {
+    machines: Vec<Machine<Channel, Channel>>,
+    routing: Routing,
+    idle_input: Option<i64>,
+    outgoing: VecDeque<Packet>,
+}
+
+impl Network {
+    /// A machine running each program, addressed by its index
+    pub fn new(programs: impl IntoIterator<Item = Vec<i64>>, routing: Routing) -> Self {
+        let programs = programs.into_iter().collect::<Vec<_>>();
+        let inputs = programs.iter().map(|_| Channel::new()).collect::<Vec<_>>();
+        let machines = programs
+            .into_iter()
+            .enumerate()
+            .map(|(address, program)| {
+                let outputs = match routing {
+                    Routing::Ring => inputs[(address + 1) % inputs.len()].clone(),
+                    Routing::Packets { .. } => Channel::new(),
+                };
+                Machine::with_io(program, inputs[address].clone(), outputs)
+            })
+            .collect();
+        Self {
+            machines,
+            routing,
+            idle_input: None,
+            outgoing: VecDeque::new(),
+        }
+    }
+
+    /// Gives machines with nothing to read `value` instead of making them wait
+    pub fn with_idle_input(mut self, value: i64) -> Self {
+        self.idle_input = Some(value);
+        self
+    }
+
+    /// In a ring, the last machine's outputs wait in the first one's inputs
+    pub fn machines(&self) -> &[Machine<Channel, Channel>] {
+        &self.machines
+    }
+
+    /// Adds to the inputs of the machine at `address`
+    pub fn send(&mut self, address: usize, values: impl IntoIterator<Item = i64>) {
+        for value in values {
+            self.machines[address].inputs.push(value);
+        }
+    }
+
+    /// Runs rounds until there's a packet for outside the network, it goes idle or every
+    /// machine halts
+    pub fn run(&mut self) -> Result<Event, IntcodeError> {
+        let mut quiet_rounds = 0;
+        loop {
+            if let Some(packet) = self.outgoing.pop_front() {
+                return Ok(Event::Packet(packet));
+            }
+            if self
+                .machines
+                .iter()
+                .all(|machine| machine.state == MachineState::HitStopInstruction)
+            {
+                return Ok(Event::Halted);
+            }
+            if quiet_rounds == QUIET_ROUNDS {
+                return Ok(Event::Idle);
+            }
+
+            // in a ring anything sent shows up as input to the next machine, if not this
+            // round then the next
+            let mut quiet = true;
+            for address in 0..self.machines.len() {
+                let machine = &mut self.machines[address];
+                if machine.state == MachineState::HitStopInstruction {
+                    continue;
+                }
+                match self.idle_input {
+                    _ if !machine.inputs.is_empty() => quiet = false,
+                    Some(value) => machine.inputs.push(value),
+                    None => {}
+                }
+                machine.run()?;
+                if let Routing::Packets { size } = self.routing {
+                    if self.route(address, size) {
+                        quiet = false;
+                    }
+                }
+            }
+            quiet_rounds = if quiet { quiet_rounds + 1 } else { 0 };
+        }
+    }
+
+    /// Runs the network with a NAT at `address`, which keeps the last packet sent to it and
+    /// sends it on to machine 0 whenever the network goes idle. `monitor` sees each packet the
+    /// NAT sends, and stops the network by returning something. Packets for any other address
+    /// outside the network are dropped.
+    ///
+    /// Gives `None` if every machine halts, or if the network goes idle before the NAT has
+    /// anything to send.
+    pub fn run_with_nat<T>(
+        &mut self,
+        address: i64,
+        mut monitor: impl FnMut(&Packet) -> Option<T>,
+    ) -> Result<Option<T>, IntcodeError> {
+        let mut last = None;
+        loop {
+            match self.run()? {
+                Event::Packet(packet) if packet.destination == address => last = Some(packet),
+                Event::Packet(_) => {}
+                Event::Halted => return Ok(None),
+                Event::Idle => {
+                    let Some(Packet { values, .. }) = &last else {
+                        return Ok(None);
+                    };
+                    let packet = Packet {
+                        destination: 0,
+                        values: values.clone(),
+                    };
+                    if let Some(result) = monitor(&packet) {
+                        return Ok(Some(result));
+                    }
+                    self.send(0, packet.values);
+                }
+            }
+        }
+    }
+
+    /// Sends on every whole packet the machine has written, returning whether there were any.
+    /// The start of a packet it hasn't finished stays in its outputs.
+    fn route(&mut self, address: usize, size: usize) -> bool {
+        let outputs = self.machines[address].outputs.clone();
+        let mut routed = false;
+        while outputs.len() > size {
+            let destination = outputs.pop().unwrap();
+            let values = (0..size).map(|_| outputs.pop().unwrap()).collect();
+            routed = true;
+            match usize::try_from(destination) {
+                Ok(address) if address < self.machines.len() => self.send(address, values),
+                _ => self.outgoing.push_back(Packet {
+                    destination,
+                    values,
+                }),
+            }
+        }
+        routed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intcode::assemble;
+
+    const MACHINES: i64 = 3;
+
+    /// Machine 0 sends `(10, 20)` to machine 1, and each machine passes on what it gets to the
+    /// next with `y` one higher, the last one sending to 255
+    fn relay() -> Network {
+        let program = assemble(&format!(
+            "
+                    in [address]
+                    jt [address], #relay
+                    out #1
+                    out #10
+                    out #20
+            relay:  in [x]
+                    eq [x], #-1, [idle]
+                    jt [idle], #relay
+                    in [y]
+                    add [y], #1, [y]
+                    add [address], #1, [next]
+                    lt [next], #{MACHINES}, [inside]
+                    jt [inside], #send
+                    add #255, #0, [next]
+            send:   out [next]
+                    out [x]
+                    out [y]
+                    jt #1, #relay
+            address: data 0
+            x:      data 0
+            y:      data 0
+            idle:   data 0
+            next:   data 0
+            inside: data 0
+            "
+        ))
+        .unwrap();
+        let programs = (0..MACHINES).map(|_| program.clone());
+        let mut network = Network::new(programs, Routing::Packets { size: 2 });
+        for address in 0..MACHINES {
+            network.send(address as usize, [address]);
+        }
+        network
+    }
+
+    #[test]
+    fn test_packets() {
+        let mut network = relay().with_idle_input(-1);
+        let packet = Packet {
+            destination: 255,
+            values: vec![10, 22],
+        };
+        assert_eq!(network.run(), Ok(Event::Packet(packet)));
+        assert_eq!(network.run(), Ok(Event::Idle));
+        assert!(network
+            .machines()
+            .iter()
+            .all(|machine| machine.outputs.is_empty()));
+
+        // without the idle input the machines just wait
+        let mut network = relay();
+        assert!(matches!(network.run(), Ok(Event::Packet(_))));
+        assert_eq!(network.run(), Ok(Event::Idle));
+        assert!(network
+            .machines()
+            .iter()
+            .all(|machine| machine.state == MachineState::NeedMoreInput));
+    }
+
+    #[test]
+    fn test_nat() {
+        let mut network = relay().with_idle_input(-1);
+        let mut sent = vec![];
+        let result = network.run_with_nat(255, |packet| {
+            sent.push(packet.values[1]);
+            (packet.values[1] > 30).then_some(packet.values[1])
+        });
+        assert_eq!(result, Ok(Some(31)));
+        assert_eq!(sent, [22, 25, 28, 31]);
+    }
+
+    #[test]
+    fn test_ring() {
+        // doubles its input, twice, then halts
+        let program = assemble(
+            "
+                    in [x]
+                    mul [x], #2, [x]
+                    out [x]
+                    in [x]
+                    mul [x], #2, [x]
+                    out [x]
+                    hlt
+            x:      data 0
+            ",
+        )
+        .unwrap();
+        let mut network = Network::new(vec![program; 3], Routing::Ring);
+        network.send(0, [1]);
+        assert_eq!(network.run(), Ok(Event::Halted));
+        // the first machine had halted by the time the last one's second output came round
+        let first = &network.machines()[0];
+        assert_eq!(first.inputs.len(), 1);
+        assert_eq!(first.inputs.pop(), Some(64));
+    }
+}
@@ -1,12 +1,17 @@
 // used in day_14
 #![feature(hash_extract_if)]
 
+pub mod days;
 pub mod intcode;
 
-use aoc_lib::get_input_year;
+use aoc_lib::{try_get_input_year, AocError};
+
+pub fn try_get_input(day: usize) -> Result<String, AocError> {
+    try_get_input_year(2019, day)
+}
 
 pub fn get_input(day: usize) -> String {
-    get_input_year(2019, day)
+    try_get_input(day).unwrap_or_else(|e| panic!("{e}"))
 }
 
 pub fn gcd(mut a: usize, mut b: usize) -> usize {
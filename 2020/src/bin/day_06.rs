@@ -1,30 +1,6 @@
-use aoc_2020::get_input;
-use itertools::Itertools;
-use std::collections::HashSet;
+use aoc_2020::days::day_06::Day06;
+use aoc_lib::solution::run;
 
 fn main() {
-    let input = get_input(6);
-
-    // Strategy: for each group, get all the unique characters (while ignoring the newlines between
-    //           people), count them, then sum all the groups
-    let part_1 = input
-        .split("\n\n")
-        .map(|group| group.chars().filter(|x| x.is_alphabetic()).unique().count())
-        .sum::<usize>();
-    dbg!(part_1);
-
-    // Strategy: for each group, create a hashset for each person, intersect them all, then count
-    //           how many are in the final intersection, then sum all the groups
-    let part_2 = input
-        .split("\n\n")
-        .map(|group| {
-            group
-                .split("\n")
-                .map(|person| person.chars().collect::<HashSet<_>>())
-                .reduce(|acc, x| acc.intersection(&x).copied().collect::<HashSet<_>>())
-                .unwrap()
-                .len()
-        })
-        .sum::<usize>();
-    dbg!(part_2);
+    run(&Day06);
 }
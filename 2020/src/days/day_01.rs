@@ -0,0 +1,50 @@
+use aoc_lib::solution::Solution;
+use itertools::Itertools;
+use std::collections::HashSet;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: usize = 2020;
+    const DAY: usize = 1;
+    const TITLE: &'static str = "Report Repair";
+
+    type Parsed<'a> = HashSet<u32>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+            .lines()
+            .map(|x| x.parse().unwrap())
+            .collect::<HashSet<u32>>()
+    }
+
+    fn part_1(numbers: &Self::Parsed<'_>) -> u32 {
+        part_1(numbers).expect("No solution found")
+    }
+
+    fn part_2(numbers: &Self::Parsed<'_>) -> u32 {
+        part_2(numbers).expect("No solution found")
+    }
+}
+
+// We could shortcut and iter for n and if (2020 - n) is present in map, return these, but we use a
+// combination method for part_2, so keep it simple
+fn part_1(numbers: &HashSet<u32>) -> Option<u32> {
+    find_group(numbers, 2, 2020).map(|x| x.iter().product())
+}
+
+fn part_2(numbers: &HashSet<u32>) -> Option<u32> {
+    find_group(numbers, 3, 2020).map(|x| x.iter().product())
+}
+
+fn find_group(numbers: &HashSet<u32>, amount: usize, target: u32) -> Option<Vec<u32>> {
+    for set in numbers.into_iter().copied().combinations(amount) {
+        if set.iter().copied().sum::<u32>() == target {
+            return Some(set);
+        }
+    }
+
+    None
+}
@@ -0,0 +1,74 @@
+use aoc_lib::solution::Solution;
+use itertools::Itertools;
+use lazy_regex::regex_captures;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: usize = 2020;
+    const DAY: usize = 2;
+    const TITLE: &'static str = "Password Philosophy";
+
+    type Parsed<'a> = Vec<(Policy, &'a str)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input.lines().map(parse_line).collect_vec()
+    }
+
+    fn part_1(input: &Self::Parsed<'_>) -> usize {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Parsed<'_>) -> usize {
+        part_2(input)
+    }
+}
+
+fn part_1(input: &[(Policy, &str)]) -> usize {
+    input
+        .into_iter()
+        .filter(|(policy, password)| policy.is_valid_v1(password))
+        .count()
+}
+
+fn part_2(input: &[(Policy, &str)]) -> usize {
+    input
+        .into_iter()
+        .filter(|(policy, password)| policy.is_valid_v2(password))
+        .count()
+}
+
+#[derive(Clone)]
+pub struct Policy {
+    character: char,
+    left: usize,
+    right: usize,
+}
+
+impl Policy {
+    fn is_valid_v1(&self, password: &str) -> bool {
+        (self.left..=self.right)
+            .contains(&password.chars().filter(|x| *x == self.character).count())
+    }
+
+    fn is_valid_v2(&self, password: &str) -> bool {
+        let password = password.chars().collect_vec();
+        (password[self.left - 1] == self.character) ^ (password[self.right - 1] == self.character)
+    }
+}
+
+fn parse_line(line: &str) -> (Policy, &str) {
+    let (_, lower, upper, c, password) = regex_captures!(r#"(\d+)-(\d+) ([a-z]): ([a-z]+)"#, line)
+        .expect(&format!("Line not in valid format: {line}"));
+
+    (
+        Policy {
+            left: lower.parse().unwrap(),
+            right: upper.parse().unwrap(),
+            character: c.chars().next().unwrap(),
+        },
+        password,
+    )
+}
@@ -0,0 +1,123 @@
+use aoc_lib::solution::Solution;
+use aoc_lib::vector::Vector;
+use std::collections::HashSet;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: usize = 2020;
+    const DAY: usize = 3;
+    const TITLE: &'static str = "Toboggan Trajectory";
+
+    type Parsed<'a> = Grid;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        build_grid(input)
+    }
+
+    fn part_1(grid: &Self::Parsed<'_>) -> usize {
+        part_1(grid)
+    }
+
+    fn part_2(grid: &Self::Parsed<'_>) -> usize {
+        part_2(grid)
+    }
+}
+
+fn part_1(grid: &Grid) -> usize {
+    Sled::new(&grid, (3, 1)).filter(|x| x.1.is_tree()).count()
+}
+
+fn part_2(grid: &Grid) -> usize {
+    let slopes = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+
+    slopes
+        .into_iter()
+        .map(|slope| Sled::new(grid, slope).filter(|x| x.1.is_tree()).count())
+        .product()
+}
+
+pub struct Grid {
+    trees: HashSet<Vector<2>>,
+    height: usize,
+    width: usize,
+}
+
+impl Grid {
+    fn get(&self, position: Vector<2>) -> Tile {
+        match self.trees.contains(&position) {
+            false => Tile::Empty,
+            true => Tile::Tree,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+enum Tile {
+    Tree,
+    Empty,
+}
+
+impl Tile {
+    fn is_tree(&self) -> bool {
+        matches!(self, Self::Tree)
+    }
+}
+
+struct Sled<'a> {
+    grid: &'a Grid,
+    position: Vector<2>,
+    slope: Vector<2>,
+}
+
+impl<'a> Sled<'a> {
+    fn new(grid: &Grid, slope: (usize, usize)) -> Sled {
+        Sled {
+            grid,
+            slope: Vector::new([slope.0 as i32, slope.1 as i32]),
+            position: Vector::ZERO,
+        }
+    }
+}
+
+impl Iterator for Sled<'_> {
+    type Item = (Vector<2>, Tile);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.position.y() > self.grid.height as i32 {
+            return None;
+        }
+
+        let result = (self.position, self.grid.get(self.position));
+
+        self.position += self.slope;
+        self.position[0] %= self.grid.width as i32;
+
+        Some(result)
+    }
+}
+
+fn build_grid(input: &str) -> Grid {
+    let mut trees = HashSet::new();
+    let width = input.lines().next().unwrap().len();
+    let height = input.lines().count();
+    for (y, line) in input.lines().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            match c {
+                '.' => {}
+                '#' => {
+                    trees.insert(Vector::new([x as i32, y as i32]));
+                }
+                _ => panic!("Invalid character {c} at line {y}, column {x}"),
+            }
+        }
+    }
+
+    Grid {
+        trees,
+        height,
+        width,
+    }
+}
@@ -0,0 +1,44 @@
+use aoc_lib::solution::Solution;
+use itertools::Itertools;
+use std::collections::HashSet;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: usize = 2020;
+    const DAY: usize = 6;
+    const TITLE: &'static str = "Custom Customs";
+
+    type Parsed<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_1(input: &Self::Parsed<'_>) -> usize {
+        // Strategy: for each group, get all the unique characters (while ignoring the newlines between
+        //           people), count them, then sum all the groups
+        input
+            .split("\n\n")
+            .map(|group| group.chars().filter(|x| x.is_alphabetic()).unique().count())
+            .sum::<usize>()
+    }
+
+    fn part_2(input: &Self::Parsed<'_>) -> usize {
+        // Strategy: for each group, create a hashset for each person, intersect them all, then count
+        //           how many are in the final intersection, then sum all the groups
+        input
+            .split("\n\n")
+            .map(|group| {
+                group
+                    .split("\n")
+                    .map(|person| person.chars().collect::<HashSet<_>>())
+                    .reduce(|acc, x| acc.intersection(&x).copied().collect::<HashSet<_>>())
+                    .unwrap()
+                    .len()
+            })
+            .sum::<usize>()
+    }
+}
@@ -0,0 +1,58 @@
+use aoc_lib::solution::Solution;
+use itertools::Itertools;
+use std::collections::HashMap;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: usize = 2020;
+    const DAY: usize = 10;
+    const TITLE: &'static str = "Adapter Array";
+
+    type Parsed<'a> = Vec<u32>;
+    type Part1 = u32;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        let mut adapters = input
+            .lines()
+            .map(|x| x.parse::<u32>().unwrap())
+            .collect_vec();
+
+        adapters.push(0);
+        adapters.push(adapters.iter().max().unwrap() + 3);
+        adapters.sort();
+        adapters
+    }
+
+    fn part_1(adapters: &Self::Parsed<'_>) -> u32 {
+        part_1(adapters)
+    }
+
+    fn part_2(adapters: &Self::Parsed<'_>) -> u64 {
+        part_2(adapters)
+    }
+}
+
+fn part_1(adapters: &[u32]) -> u32 {
+    let mut diffs = HashMap::<u32, u32>::new();
+    for (left, right) in adapters.iter().tuple_windows() {
+        if right - left > 3 {
+            panic!("Cannot jump from jolt of {left} to {right}")
+        }
+        *diffs.entry(right - left).or_default() += 1;
+    }
+
+    diffs.get(&1).unwrap() * diffs.get(&3).unwrap()
+}
+
+fn part_2(adapters: &[u32]) -> u64 {
+    adapters
+        .into_iter()
+        .tuple_windows()
+        .collect_vec()
+        .split(|(x, y)| **y - **x == 3)
+        .filter(|x| x.len() > 1)
+        .map(|n| 2u64.pow(n.len() as u32 - 1) - (n.len() as u64 / 4))
+        .product::<u64>()
+}
@@ -0,0 +1,108 @@
+use aoc_lib::automaton::Bounded;
+use aoc_lib::grid::{DenseGrid, ToChar};
+use aoc_lib::solution::Solution;
+use aoc_lib::vector::Neighbourhood;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: usize = 2020;
+    const DAY: usize = 11;
+    const TITLE: &'static str = "Seating System";
+
+    type Parsed<'a> = DenseGrid<Tile>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        DenseGrid::parse(input, |c| Tile::try_from(c).unwrap())
+    }
+
+    fn part_1(grid: &Self::Parsed<'_>) -> usize {
+        part_1(grid.clone())
+    }
+
+    fn part_2(grid: &Self::Parsed<'_>) -> usize {
+        part_2(grid.clone())
+    }
+}
+
+fn part_1(grid: DenseGrid<Tile>) -> usize {
+    occupied_when_settled(Bounded::new(grid, Neighbourhood::Moore), 4)
+}
+
+fn part_2(grid: DenseGrid<Tile>) -> usize {
+    occupied_when_settled(Bounded::line_of_sight(grid, |x| *x != Tile::Floor), 5)
+}
+
+fn occupied_when_settled(mut world: Bounded<Tile>, tolerance: usize) -> usize {
+    // an oscillating seat map never settles, so stop at the first repeated layout
+    world.run_until_repeat(|tile, neighbours| {
+        let occupied_neighbours = neighbours.iter().filter(|x| x.is_occupied()).count();
+        match (tile, occupied_neighbours) {
+            (Tile::EmptySeat, 0) => Tile::OccupiedSeat,
+            (Tile::OccupiedSeat, n) if n >= tolerance => Tile::EmptySeat,
+            _ => *tile,
+        }
+    });
+    world.grid().values().filter(|x| x.is_occupied()).count()
+}
+
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+pub enum Tile {
+    EmptySeat,
+    OccupiedSeat,
+    Floor,
+}
+
+impl Tile {
+    fn is_occupied(&self) -> bool {
+        match self {
+            Tile::EmptySeat => false,
+            Tile::OccupiedSeat => true,
+            Tile::Floor => false,
+        }
+    }
+}
+
+impl ToChar for Tile {
+    fn to_char(&self) -> char {
+        match self {
+            Tile::EmptySeat => 'L',
+            Tile::OccupiedSeat => '#',
+            Tile::Floor => '.',
+        }
+    }
+}
+
+impl TryFrom<char> for Tile {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        use Tile::*;
+        match value {
+            '#' => Ok(OccupiedSeat),
+            'L' => Ok(EmptySeat),
+            '.' => Ok(Floor),
+            _ => Err(()),
+        }
+    }
+}
+
+#[test]
+fn test_example() {
+    let grid = Day11::parse(
+        "L.LL.LL.LL\n\
+         LLLLLLL.LL\n\
+         L.L.L..L..\n\
+         LLLL.LL.LL\n\
+         L.LL.LL.LL\n\
+         L.LLLLL.LL\n\
+         ..L.L.....\n\
+         LLLLLLLLLL\n\
+         L.LLLLLL.L\n\
+         L.LLLLL.LL",
+    );
+    assert_eq!(Day11::part_1(&grid), 37);
+    assert_eq!(Day11::part_2(&grid), 26);
+}
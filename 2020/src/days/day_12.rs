@@ -0,0 +1,115 @@
+use aoc_lib::direction::{Direction4, Turtle};
+use aoc_lib::solution::Solution;
+use aoc_lib::vector::Vector;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: usize = 2020;
+    const DAY: usize = 12;
+    const TITLE: &'static str = "Rain Risk";
+
+    type Parsed<'a> = Vec<Instruction>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+            .lines()
+            .map(Instruction::try_from)
+            .try_collect()
+            .unwrap()
+    }
+
+    fn part_1(instructions: &Self::Parsed<'_>) -> usize {
+        part_1(starting_ship(), instructions)
+    }
+
+    fn part_2(instructions: &Self::Parsed<'_>) -> usize {
+        part_2(starting_ship(), instructions)
+    }
+}
+
+fn starting_ship() -> Ship {
+    Ship {
+        turtle: Turtle::new(Vector::ZERO, Direction4::Right),
+        waypoint_offset: Vector::new([10, -1]),
+    }
+}
+
+fn part_1(mut ship: Ship, instructions: &[Instruction]) -> usize {
+    for instruction in instructions {
+        ship.apply_instruction_v1(*instruction);
+    }
+
+    ship.turtle.position.manhattan_distance(Vector::ZERO) as usize
+}
+
+fn part_2(mut ship: Ship, instructions: &[Instruction]) -> usize {
+    for instruction in instructions {
+        ship.apply_instruction_v2(*instruction);
+    }
+
+    ship.turtle.position.manhattan_distance(Vector::ZERO) as usize
+}
+
+#[derive(Clone, Debug)]
+struct Ship {
+    turtle: Turtle,
+    waypoint_offset: Vector<2>,
+}
+
+impl Ship {
+    fn apply_instruction_v1(&mut self, instruction: Instruction) {
+        match instruction {
+            Instruction::TurnLeft(degrees) => self.turtle.turn(-degrees / 90),
+            Instruction::TurnRight(degrees) => self.turtle.turn(degrees / 90),
+            Instruction::MoveDirection(direction, amount) => self.turtle.shift(direction, amount),
+            Instruction::MoveForwards(amount) => self.turtle.forward(amount),
+        }
+    }
+
+    fn apply_instruction_v2(&mut self, instruction: Instruction) {
+        match instruction {
+            Instruction::TurnLeft(degrees) => {
+                self.waypoint_offset = self.waypoint_offset.rotate(-degrees / 90);
+            }
+            Instruction::TurnRight(degrees) => {
+                self.waypoint_offset = self.waypoint_offset.rotate(degrees / 90);
+            }
+            Instruction::MoveDirection(direction, amount) => {
+                self.waypoint_offset += direction.vector() * amount;
+            }
+            Instruction::MoveForwards(amount) => {
+                self.turtle.position += self.waypoint_offset * amount;
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub enum Instruction {
+    TurnLeft(i32),
+    TurnRight(i32),
+    MoveDirection(Direction4, i32),
+    MoveForwards(i32),
+}
+
+impl TryFrom<&str> for Instruction {
+    type Error = ();
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        use Instruction::*;
+        let number = value[1..].parse::<i32>().unwrap();
+        Ok(match value.chars().next() {
+            None => return Err(()),
+            Some(c @ ('N' | 'E' | 'S' | 'W')) => {
+                MoveDirection(Direction4::try_from(c).unwrap(), number)
+            }
+            Some('L') => TurnLeft(number),
+            Some('R') => TurnRight(number),
+            Some('F') => MoveForwards(number),
+            Some(_) => return Err(()),
+        })
+    }
+}
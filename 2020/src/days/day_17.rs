@@ -0,0 +1,56 @@
+use aoc_lib::automaton::{life, ActiveSet};
+use aoc_lib::solution::Solution;
+use aoc_lib::vector::{Neighbourhood, Vector};
+use std::collections::HashSet;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: usize = 2020;
+    const DAY: usize = 17;
+    const TITLE: &'static str = "Conway Cubes";
+
+    type Parsed<'a> = (HashSet<Vector<3>>, HashSet<Vector<4>>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        let mut grid_3d = HashSet::new();
+        let mut grid_4d = HashSet::new();
+        for (y, line) in input.lines().enumerate() {
+            for (x, cha) in line.chars().enumerate() {
+                match cha {
+                    '.' => {}
+                    '#' => {
+                        grid_3d.insert(Vector::new([x as i32, y as i32, 0_i32]));
+                        grid_4d.insert(Vector::new([x as i32, y as i32, 0_i32, 0i32]));
+                    }
+                    _ => panic!("Invalid character: {cha} at line {y} char {x}"),
+                };
+            }
+        }
+        (grid_3d, grid_4d)
+    }
+
+    fn part_1((grid_3d, _grid_4d): &Self::Parsed<'_>) -> usize {
+        part_1(grid_3d.clone())
+    }
+
+    fn part_2((_grid_3d, grid_4d): &Self::Parsed<'_>) -> usize {
+        part_2(grid_4d.clone())
+    }
+}
+
+fn part_1(grid: HashSet<Vector<3>>) -> usize {
+    run_simulation(grid)
+}
+
+fn part_2(grid: HashSet<Vector<4>>) -> usize {
+    run_simulation(grid)
+}
+
+fn run_simulation<const DIMENSIONS: usize>(grid: HashSet<Vector<DIMENSIONS>>) -> usize {
+    let mut world = ActiveSet::new(grid, Neighbourhood::Moore);
+    world.run(6, life(&[3], &[2, 3]));
+    world.len()
+}
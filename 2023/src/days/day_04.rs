@@ -0,0 +1,59 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_lib::solution::Solution;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: usize = 2023;
+    const DAY: usize = 4;
+    const TITLE: &'static str = "Scratchcards";
+
+    type Parsed<'a> = &'a str;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_1(input: &Self::Parsed<'_>) -> u32 {
+        solve(input).0
+    }
+
+    fn part_2(input: &Self::Parsed<'_>) -> u32 {
+        solve(input).1
+    }
+}
+
+fn solve(input: &str) -> (u32, u32) {
+    let mut part_a_total = 0;
+    let mut card_counts: HashMap<u32, u32> = HashMap::new();
+
+    for (card_number, line) in input.lines().enumerate() {
+        let card_number = card_number + 1;
+
+        let matched_numbers = match line.char_indices().skip_while(|(_, c)| c != &':').nth(1) {
+            Some((pos, _)) => &line[pos..],
+            None => "",
+        }
+        .split('|')
+        .map(|s| HashSet::from_iter(s.split_whitespace().map(|s| s.parse::<u32>().unwrap())))
+        .reduce(|acc: HashSet<u32>, x| acc.intersection(&x).cloned().collect())
+        .unwrap()
+        .len();
+
+        if matched_numbers > 0 {
+            part_a_total += 2_u32.pow(matched_numbers as u32 - 1);
+        }
+
+        for i in (card_number + 1)..(card_number + 1 + matched_numbers) {
+            *card_counts.entry(i as u32).or_insert(1) +=
+                *card_counts.entry(card_number as u32).or_insert(1);
+        }
+    }
+
+    let part_b_total = card_counts.iter().fold(0_u32, |acc, (_k, v)| acc + v);
+
+    (part_a_total, part_b_total)
+}
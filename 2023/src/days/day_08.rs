@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+
+use aoc_lib::solution::{NoAnswer, Solution};
+use itertools::Itertools;
+use num::Integer;
+
+#[derive(Debug)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn from_char(cha: char) -> Self {
+        match cha {
+            'L' => Direction::Left,
+            'R' => Direction::Right,
+            _ => panic!("invalid direction given: \"{}\"", cha),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Waypoint {
+    name: String,
+    left: String,
+    right: String,
+}
+
+impl Waypoint {
+    fn get_destination(&self, direction: &Direction) -> &str {
+        match direction {
+            Direction::Left => &self.left,
+            Direction::Right => &self.right,
+        }
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: usize = 2023;
+    const DAY: usize = 8;
+    const TITLE: &'static str = "Haunted Wasteland";
+
+    type Parsed<'a> = (Vec<Direction>, HashMap<String, Waypoint>);
+    type Part1 = NoAnswer;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_1(_parsed: &Self::Parsed<'_>) -> NoAnswer {
+        NoAnswer
+    }
+
+    fn part_2((directions, map): &Self::Parsed<'_>) -> usize {
+        part_2(directions, map)
+    }
+}
+
+fn parse_input(input: &str) -> (Vec<Direction>, HashMap<String, Waypoint>) {
+    let mut input = input.lines();
+    let mut map: HashMap<String, Waypoint> = HashMap::new();
+    let directions: Vec<Direction> = input
+        .next()
+        .unwrap()
+        .chars()
+        .map(Direction::from_char)
+        .collect();
+    input.next().unwrap();
+
+    for line in input {
+        let (waypoint_name, points) = line.split(" = ").collect_tuple().unwrap();
+        let (left, right) = points
+            .trim_matches(|c| c == ')' || c == '(')
+            .split(", ")
+            .collect_tuple()
+            .unwrap();
+        map.insert(
+            waypoint_name.to_string(),
+            Waypoint {
+                name: waypoint_name.to_string(),
+                left: left.to_string(),
+                right: right.to_string(),
+            },
+        );
+    }
+
+    (directions, map)
+}
+
+fn part_2(directions: &[Direction], map: &HashMap<String, Waypoint>) -> usize {
+    let locations = map
+        .keys()
+        .filter(|x| x.ends_with('A'))
+        .map(|x| map.get(x).unwrap())
+        .collect_vec();
+    let mut counts = vec![];
+
+    for location in locations {
+        let mut current_location = location;
+        let mut moved = 0usize;
+        for (idx, direction) in directions.iter().cycle().enumerate() {
+            moved = idx;
+            if current_location.name.ends_with('Z') {
+                break;
+            }
+            current_location = map
+                .get(current_location.get_destination(direction))
+                .unwrap();
+        }
+        counts.push(moved)
+    }
+
+    counts.into_iter().reduce(|a, b| a.lcm(&b)).unwrap()
+}
@@ -0,0 +1,82 @@
+use aoc_lib::solution::Solution;
+use itertools::Itertools;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: usize = 2023;
+    const DAY: usize = 9;
+    const TITLE: &'static str = "Mirage Maintenance";
+
+    type Parsed<'a> = &'a str;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_1(input: &Self::Parsed<'_>) -> i32 {
+        part_a(input)
+    }
+
+    fn part_2(input: &Self::Parsed<'_>) -> i32 {
+        part_b(input)
+    }
+}
+
+fn calculate_forwards_sequence(sequence: &Vec<i32>) -> Vec<i32> {
+    sequence
+        .iter()
+        .tuple_windows::<(&i32, &i32)>()
+        .map(|(a, b)| b - a)
+        .collect()
+}
+
+fn calculate_increase_sequence_n(sequence: &Vec<i32>) -> Vec<Vec<i32>> {
+    let mut out = vec![sequence.clone()];
+    loop {
+        let next = out.last().unwrap();
+        if next.iter().all(|x| x == &0) {
+            break;
+        }
+        out.push(calculate_forwards_sequence(next));
+    }
+    out
+}
+
+fn get_next_in_top_sequence(sequences: Vec<Vec<i32>>) -> i32 {
+    let mut last_extra = 0_i32;
+    for sequence in sequences.iter().rev().skip(1) {
+        last_extra += sequence.last().unwrap();
+    }
+    last_extra
+}
+
+fn part_a(input: &str) -> i32 {
+    input
+        .lines()
+        .map(|s| {
+            s.split_whitespace()
+                .map(|s| s.parse::<i32>().unwrap())
+                .collect_vec()
+        })
+        .map(|sequence| get_next_in_top_sequence(calculate_increase_sequence_n(&sequence)))
+        .sum()
+}
+
+fn part_b(input: &str) -> i32 {
+    input
+        .lines()
+        .map(|s| {
+            s.split_whitespace()
+                .map(|s| s.parse::<i32>().unwrap())
+                .collect_vec()
+        })
+        .map(|sequence| {
+            get_next_in_top_sequence(calculate_increase_sequence_n(
+                &sequence.into_iter().rev().collect_vec(),
+            ))
+        })
+        .sum()
+}
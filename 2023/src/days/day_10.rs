@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+use aoc_lib::direction::Direction4;
+use aoc_lib::grid::DenseGrid;
+use aoc_lib::solution::{NoAnswer, Solution};
+use aoc_lib::vector::Vector;
+
+#[derive(Debug, Clone)]
+enum Tile {
+    Vertical,
+    Horizontal,
+    NorthToEast,
+    SouthToEast,
+    SouthToWest,
+    NorthToWest,
+    Ground,
+    StartingPoint,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
+            '|' => Self::Vertical,
+            '-' => Self::Horizontal,
+            'L' => Self::NorthToEast,
+            'J' => Self::NorthToWest,
+            '7' => Self::SouthToWest,
+            'F' => Self::SouthToEast,
+            '.' => Self::Ground,
+            'S' => Self::StartingPoint,
+            _ => return Err(()),
+        })
+    }
+}
+
+impl Tile {
+    fn could_connect_to(&self, direction: Direction4) -> bool {
+        use Direction4::*;
+        matches!(
+            (self, direction),
+            (Tile::Vertical, Up | Down)
+                | (Tile::Horizontal, Right | Left)
+                | (Tile::NorthToEast, Up | Right)
+                | (Tile::NorthToWest, Up | Left)
+                | (Tile::SouthToWest, Down | Left)
+                | (Tile::SouthToEast, Down | Right)
+                | (Tile::StartingPoint, _)
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct Grid {
+    inner: DenseGrid<Tile>,
+    start: Vector<2>,
+}
+
+impl Grid {
+    fn get_connected_neighbours(
+        &self,
+        coords: Vector<2>,
+    ) -> impl Iterator<Item = Vector<2>> + use<'_> {
+        let current_tile = &self.inner[coords];
+        Direction4::ALL.into_iter().filter_map(move |direction| {
+            let neighbour = coords + direction.vector();
+            let neighbour_tile = self.inner.get(neighbour)?;
+            (neighbour_tile.could_connect_to(direction.reverse())
+                && current_tile.could_connect_to(direction))
+            .then_some(neighbour)
+        })
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: usize = 2023;
+    const DAY: usize = 10;
+    const TITLE: &'static str = "Pipe Maze";
+
+    type Parsed<'a> = Grid;
+    type Part1 = u32;
+    type Part2 = NoAnswer;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_1(grid: &Self::Parsed<'_>) -> u32 {
+        part_a(grid)
+    }
+
+    fn part_2(_grid: &Self::Parsed<'_>) -> NoAnswer {
+        NoAnswer
+    }
+}
+
+fn parse_input(input: &str) -> Grid {
+    let inner = DenseGrid::parse(input, |c| Tile::try_from(c).unwrap());
+    let start = inner
+        .iter()
+        .find_map(|(point, tile)| matches!(tile, Tile::StartingPoint).then_some(point))
+        .unwrap();
+
+    Grid { inner, start }
+}
+
+fn part_a(grid: &Grid) -> u32 {
+    let mut visited: HashMap<Vector<2>, u32> = HashMap::new();
+    let mut to_visit: Vec<Vector<2>> = vec![grid.start];
+    let mut part_a = 0u32;
+
+    loop {
+        let mut next_visits: Vec<Vector<2>> = vec![];
+        for coords in to_visit {
+            visited.insert(coords, part_a);
+            for neighbour_coords in grid.get_connected_neighbours(coords) {
+                if visited.contains_key(&neighbour_coords) {
+                    continue;
+                }
+                next_visits.push(neighbour_coords);
+            }
+        }
+        if next_visits.is_empty() {
+            break;
+        }
+        to_visit = next_visits;
+        part_a += 1;
+    }
+
+    part_a
+}
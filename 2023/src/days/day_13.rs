@@ -0,0 +1,117 @@
+use aoc_lib::grid::DenseGrid;
+use aoc_lib::paragraphs::Paragraphs;
+use aoc_lib::solution::Solution;
+use itertools::Itertools;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: usize = 2023;
+    const DAY: usize = 13;
+    const TITLE: &'static str = "Point of Incidence";
+
+    type Parsed<'a> = &'a str;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_1(input: &Self::Parsed<'_>) -> u32 {
+        solve(input).0
+    }
+
+    fn part_2(input: &Self::Parsed<'_>) -> u32 {
+        solve(input).1
+    }
+}
+
+fn solve(input: &str) -> (u32, u32) {
+    let mut part_a = 0u32;
+    let mut part_b = 0u32;
+
+    for puzzle in input
+        .paragraphs()
+        .map(|mut lines| DenseGrid::parse(&lines.join("\n"), |c| c))
+    {
+        let transposed = puzzle.transpose();
+        if let Some(horizontal_reflection_idx) = get_reflection_a(&rows(&puzzle)) {
+            part_a += horizontal_reflection_idx * 100
+        } else if let Some(vertical_reflection_idx) = get_reflection_a(&rows(&transposed)) {
+            part_a += vertical_reflection_idx;
+        } else {
+            panic!("No reflection found");
+        }
+        if let Some(horizontal_reflection_idx) = get_reflection_b(&rows(&puzzle)) {
+            part_b += horizontal_reflection_idx * 100
+        } else if let Some(vertical_reflection_idx) = get_reflection_b(&rows(&transposed)) {
+            part_b += vertical_reflection_idx;
+        } else {
+            panic!("No reflection found");
+        }
+    }
+
+    (part_a, part_b)
+}
+
+fn rows(grid: &DenseGrid<char>) -> Vec<&[char]> {
+    grid.rows().collect()
+}
+
+fn get_reflection_a(lines: &[&[char]]) -> Option<u32> {
+    for ((a_idx, a), (b_idx, b)) in lines.iter().enumerate().tuple_windows() {
+        if a != b {
+            continue;
+        }
+        if lines
+            .iter()
+            .rev()
+            .skip(lines.len() - 1 - a_idx)
+            .zip(lines.iter().skip(b_idx))
+            .map(|(a, b)| a == b)
+            .all(|x| x)
+        {
+            return Some(b_idx as u32);
+        } else {
+            continue;
+        }
+    }
+    None
+}
+
+fn get_reflection_b(lines: &[&[char]]) -> Option<u32> {
+    for ((a_idx, a), (b_idx, b)) in lines.iter().enumerate().tuple_windows() {
+        if a != b && !is_similar(a, b) {
+            continue;
+        }
+        let mut identities = lines
+            .iter()
+            .rev()
+            .skip(lines.len() - 1 - a_idx)
+            .zip(lines.iter().skip(b_idx))
+            .map(|(a, b)| (a == b, is_similar(a, b)));
+        if identities
+            .clone()
+            .filter(|(_same, similar)| *similar)
+            .count()
+            == 1
+            && identities.all(|(similar, same)| similar || same)
+        {
+            return Some(b_idx as u32);
+        } else {
+            continue;
+        }
+    }
+    None
+}
+
+fn is_similar(line_a: &[char], line_b: &[char]) -> bool {
+    // if only one difference, then true, otherwise false
+    line_a
+        .iter()
+        .zip(line_b)
+        .filter_map(|(a, b)| (a != b).then_some(()))
+        .count()
+        == 1
+}
@@ -0,0 +1,96 @@
+use aoc_lib::cycle;
+use aoc_lib::grid::{DenseGrid, ToChar};
+use aoc_lib::solution::{NoAnswer, Solution};
+use aoc_lib::vector::Vector;
+
+#[derive(Clone, Hash, PartialEq, Eq)]
+enum Tile {
+    CubeRock,
+    RoundRock,
+    Empty,
+}
+
+impl ToChar for Tile {
+    fn to_char(&self) -> char {
+        match self {
+            Tile::CubeRock => '#',
+            Tile::RoundRock => 'O',
+            Tile::Empty => '.',
+        }
+    }
+}
+
+type Grid = DenseGrid<Tile>;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: usize = 2023;
+    const DAY: usize = 14;
+    const TITLE: &'static str = "Parabolic Reflector Dish";
+
+    type Parsed<'a> = &'a str;
+    type Part1 = NoAnswer;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_1(_input: &Self::Parsed<'_>) -> NoAnswer {
+        NoAnswer
+    }
+
+    fn part_2(input: &Self::Parsed<'_>) -> usize {
+        part_2(parse_grid(input))
+    }
+}
+
+fn parse_grid(input: &str) -> Grid {
+    DenseGrid::parse(input, |c| match c {
+        '#' => Tile::CubeRock,
+        'O' => Tile::RoundRock,
+        '.' => Tile::Empty,
+        _ => panic!("Unknown character"),
+    })
+}
+
+fn part_2(grid: Grid) -> usize {
+    let grid = cycle::nth_state(grid, spin_cycle, 1_000_000_000);
+    calculate_weight_north(&grid)
+}
+
+// turning the grid clockwise after each tilt brings west, then south, then east round to the
+// north, and the fourth turn puts it back the way it was
+fn spin_cycle(grid: &Grid) -> Grid {
+    let mut grid = grid.clone();
+    for _ in 0..4 {
+        tilt_north(&mut grid);
+        grid = grid.rotate_90();
+    }
+    grid
+}
+
+fn tilt_north(grid: &mut Grid) {
+    for x in 0..grid.width() as i32 {
+        let mut next_empty_space = 0;
+        for y in 0..grid.height() as i32 {
+            match grid[Vector::new([x, y])] {
+                Tile::CubeRock => next_empty_space = y + 1,
+                Tile::RoundRock => {
+                    grid[Vector::new([x, y])] = Tile::Empty;
+                    grid[Vector::new([x, next_empty_space])] = Tile::RoundRock;
+                    next_empty_space += 1;
+                }
+                Tile::Empty => {}
+            }
+        }
+    }
+}
+
+fn calculate_weight_north(grid: &Grid) -> usize {
+    grid.iter()
+        .filter(|(_, tile)| **tile == Tile::RoundRock)
+        .map(|(point, _)| grid.height() - point.y() as usize)
+        .sum()
+}
@@ -0,0 +1,155 @@
+use std::collections::HashSet;
+use std::fmt::{self};
+
+use aoc_lib::direction::{Direction4, Turtle};
+use aoc_lib::grid::DenseGrid;
+use aoc_lib::solution::Solution;
+use aoc_lib::vector::Vector;
+use itertools::Itertools;
+
+#[derive(Clone)]
+enum Tile {
+    Empty,
+    BottomLeftToTopRightMirror,
+    BottomRightToTopLeftMirror,
+    HorizontalSplitter,
+    VerticalSplitter,
+}
+
+#[derive(Clone)]
+pub struct Grid {
+    tiles: DenseGrid<Tile>,
+    energised: HashSet<Turtle>,
+}
+
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rendered = self.tiles.render(|tile| match tile {
+            Tile::Empty => '.',
+            Tile::BottomLeftToTopRightMirror => '/',
+            Tile::BottomRightToTopLeftMirror => '\\',
+            Tile::HorizontalSplitter => '-',
+            Tile::VerticalSplitter => '|',
+        });
+        for (y, line) in rendered.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                let energised = self.is_energised(Vector::new([x as i32, y as i32]));
+                write!(f, "{}", if energised { '#' } else { c })?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Grid {
+    fn is_energised(&self, point: Vector<2>) -> bool {
+        Direction4::ALL
+            .into_iter()
+            .any(|dir| self.energised.contains(&Turtle::new(point, dir)))
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: usize = 2023;
+    const DAY: usize = 16;
+    const TITLE: &'static str = "The Floor Will Be Lava";
+
+    type Parsed<'a> = Grid;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        Grid {
+            tiles: DenseGrid::parse(input, |c| match c {
+                '.' => Tile::Empty,
+                '/' => Tile::BottomLeftToTopRightMirror,
+                '\\' => Tile::BottomRightToTopLeftMirror,
+                '-' => Tile::HorizontalSplitter,
+                '|' => Tile::VerticalSplitter,
+                _ => panic!("Invalid character: {c}"),
+            }),
+            energised: HashSet::new(),
+        }
+    }
+
+    fn part_1(grid: &Self::Parsed<'_>) -> usize {
+        calculate_energised(grid.clone(), Vector::new([0, 0]), Direction4::Right)
+    }
+
+    fn part_2(grid: &Self::Parsed<'_>) -> usize {
+        let mut max_energised = 0usize;
+        let (x_len, y_len) = (grid.tiles.width() as i32, grid.tiles.height() as i32);
+
+        for y in 0..y_len {
+            let energised =
+                calculate_energised(grid.clone(), Vector::new([0, y]), Direction4::Right);
+            max_energised = max_energised.max(energised);
+            let energised =
+                calculate_energised(grid.clone(), Vector::new([x_len - 1, y]), Direction4::Left);
+            max_energised = max_energised.max(energised);
+        }
+        for x in 0..x_len {
+            let energised =
+                calculate_energised(grid.clone(), Vector::new([x, 0]), Direction4::Down);
+            max_energised = max_energised.max(energised);
+            let energised =
+                calculate_energised(grid.clone(), Vector::new([x, y_len - 1]), Direction4::Up);
+            max_energised = max_energised.max(energised);
+        }
+
+        max_energised
+    }
+}
+
+fn calculate_energised(
+    mut grid: Grid,
+    start_point: Vector<2>,
+    start_direction: Direction4,
+) -> usize {
+    let mut next_visit: HashSet<Turtle> = HashSet::new();
+    next_visit.insert(Turtle::new(start_point, start_direction));
+
+    loop {
+        let this_visit = std::mem::take(&mut next_visit)
+            .into_iter()
+            .filter(|beam| grid.tiles.contains(beam.position))
+            .collect::<Vec<_>>();
+
+        for beam in this_visit {
+            if !grid.energised.insert(beam) {
+                continue;
+            };
+            let vertical = matches!(beam.heading, Direction4::Up | Direction4::Down);
+            // quarter turns to the right for each beam leaving the tile
+            let turns: &[i32] = match grid.tiles[beam.position] {
+                Tile::Empty => &[0],
+                Tile::BottomLeftToTopRightMirror if vertical => &[1],
+                Tile::BottomLeftToTopRightMirror => &[-1],
+                Tile::BottomRightToTopLeftMirror if vertical => &[-1],
+                Tile::BottomRightToTopLeftMirror => &[1],
+                Tile::HorizontalSplitter if vertical => &[-1, 1],
+                Tile::VerticalSplitter if !vertical => &[-1, 1],
+                Tile::HorizontalSplitter | Tile::VerticalSplitter => &[0],
+            };
+            for turn in turns {
+                let mut next = beam;
+                next.turn(*turn);
+                next.forward(1);
+                next_visit.insert(next);
+            }
+        }
+
+        if next_visit.is_empty() {
+            break;
+        }
+    }
+
+    grid.energised
+        .iter()
+        .map(|beam| beam.position)
+        .unique()
+        .count()
+}
@@ -0,0 +1,76 @@
+use aoc_lib::direction::{Direction4, Turtle};
+use aoc_lib::grid::DenseGrid;
+use aoc_lib::solution::Solution;
+use aoc_lib::vector::Vector;
+use pathfinding::directed::dijkstra::dijkstra;
+
+pub struct Grid {
+    inner: DenseGrid<u32>,
+}
+
+impl Grid {
+    /// Turning either way, then going between `min` and `max` blocks in a straight line
+    fn moves(&self, min: i32, max: i32, crucible: &Turtle) -> Vec<(Turtle, u32)> {
+        let mut out = vec![];
+        for turn in [-1, 1] {
+            let mut crucible = *crucible;
+            crucible.turn(turn);
+            let mut total_cost = 0u32;
+            for (i, new_pos) in (1..).zip(crucible.walk(max)) {
+                if let Some(cost) = self.inner.get(new_pos) {
+                    total_cost += cost;
+                    if i >= min {
+                        out.push((Turtle::new(new_pos, crucible.heading), total_cost));
+                    }
+                }
+            }
+        }
+        out
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: usize = 2023;
+    const DAY: usize = 17;
+    const TITLE: &'static str = "Clumsy Crucible";
+
+    type Parsed<'a> = Grid;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        Grid {
+            inner: DenseGrid::parse(input, |c| c.to_digit(10).unwrap()),
+        }
+    }
+
+    fn part_1(grid: &Self::Parsed<'_>) -> u32 {
+        get_answer(grid, 1, 3)
+    }
+
+    fn part_2(grid: &Self::Parsed<'_>) -> u32 {
+        get_answer(grid, 4, 10)
+    }
+}
+
+fn get_answer(grid: &Grid, min: i32, max: i32) -> u32 {
+    let goal = Vector::new([
+        grid.inner.width() as i32 - 1,
+        grid.inner.height() as i32 - 1,
+    ]);
+    [Direction4::Down, Direction4::Right]
+        .into_iter()
+        .map(|heading| {
+            dijkstra(
+                &Turtle::new(Vector::ZERO, heading),
+                |crucible| grid.moves(min, max, crucible),
+                |crucible| crucible.position == goal,
+            )
+            .unwrap()
+            .1
+        })
+        .min()
+        .unwrap()
+}
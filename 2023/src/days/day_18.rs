@@ -0,0 +1,90 @@
+use aoc_lib::solution::Solution;
+use aoc_lib::vector::Vector;
+use itertools::Itertools;
+use num::Integer;
+
+type Point = Vector<2, i64>;
+
+fn unit(direction: &str) -> Point {
+    match direction {
+        "L" => Vector::new([-1, 0]),
+        "R" => Vector::new([1, 0]),
+        "U" => Vector::new([0, 1]),
+        "D" => Vector::new([0, -1]),
+        _ => panic!("Invalid direction: {direction}"),
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: usize = 2023;
+    const DAY: usize = 18;
+    const TITLE: &'static str = "Lavaduct Lagoon";
+
+    type Parsed<'a> = &'a str;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_1(input: &Self::Parsed<'_>) -> i64 {
+        let (corners, edge_length) = parse_input(input, false);
+        get_area(&corners, edge_length)
+    }
+
+    fn part_2(input: &Self::Parsed<'_>) -> i64 {
+        let (corners, edge_length) = parse_input(input, true);
+        get_area(&corners, edge_length)
+    }
+}
+
+fn parse_input(input: &str, part_b: bool) -> (Vec<Point>, i64) {
+    let mut corners: Vec<Point> = vec![Vector::ZERO];
+    let mut edge_length = 1;
+
+    for line in input.lines() {
+        let (direction, distance) = if part_b {
+            parse_line_b(line)
+        } else {
+            parse_line_a(line)
+        };
+        let point = *corners.last().unwrap() + unit(direction) * distance;
+        edge_length += distance;
+        corners.push(point);
+    }
+
+    (corners, edge_length)
+}
+
+fn parse_line_a(line: &str) -> (&str, i64) {
+    let (direction, distance, _) = line.split_whitespace().collect_tuple().unwrap();
+    (direction, distance.parse().unwrap())
+}
+
+fn parse_line_b(line: &str) -> (&str, i64) {
+    let (_, _, colour) = line.split_whitespace().collect_tuple().unwrap();
+    (
+        match colour.chars().nth(7).unwrap() {
+            '0' => "R",
+            '1' => "D",
+            '2' => "L",
+            '3' => "U",
+            x => panic!("Invalid direction: {x}"),
+        },
+        i64::from_str_radix(&colour[2..7], 16).unwrap(),
+    )
+}
+
+fn get_area(corners: &[Point], edge_length: i64) -> i64 {
+    let mut total_area = 0;
+    for (point_a, point_b) in corners.iter().circular_tuple_windows() {
+        // matrix determinant
+        total_area += (point_a.x() * point_b.y()) - (point_a.y() * point_b.x());
+    }
+    total_area = total_area.abs() + edge_length;
+    // div_ceil only available on unsigned types for now, feature int_rounding on nightly
+    (total_area as u64).div_ceil(2) as i64
+}
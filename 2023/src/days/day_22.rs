@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+use aoc_lib::solution::{NoAnswer, Solution};
+use aoc_lib::vector::Vector;
+use itertools::Itertools;
+
+#[derive(Debug)]
+struct Brick {
+    name: String,
+    start: Vector<3>,
+    end: Vector<3>,
+}
+
+impl Brick {
+    fn get_top_faces(&self) -> Vec<Vector<3>> {
+        let low = self.start.min(self.end);
+        let high = self.start.max(self.end);
+        let mut out = vec![];
+        for x in low.x()..=high.x() {
+            for y in low.y()..=high.y() {
+                out.push(Vector::new([x, y, high.z()]))
+            }
+        }
+        out
+    }
+
+    fn down(&self, amount: i32) -> Self {
+        let offset = Vector::unit(2) * amount;
+        Self {
+            name: self.name.clone(),
+            start: self.start - offset,
+            end: self.end - offset,
+        }
+    }
+
+    fn get_lowest_z(&self) -> i32 {
+        self.start.z().min(self.end.z())
+    }
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const YEAR: usize = 2023;
+    const DAY: usize = 22;
+    const TITLE: &'static str = "Sand Slabs";
+
+    type Parsed<'a> = &'a str;
+    type Part1 = NoAnswer;
+    type Part2 = NoAnswer;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_1(input: &Self::Parsed<'_>) -> NoAnswer {
+        let mut bricks = parse_input(input);
+        settle(&mut bricks);
+        NoAnswer
+    }
+
+    fn part_2(_input: &Self::Parsed<'_>) -> NoAnswer {
+        NoAnswer
+    }
+}
+
+fn settle(bricks: &mut [Brick]) {
+    let mut heightmap: HashMap<(i32, i32), i32> = HashMap::new();
+
+    for brick in bricks.iter_mut() {
+        let mut min_height = 1;
+
+        for face in brick.get_top_faces() {
+            min_height = min_height.max(*heightmap.get(&(face.x(), face.y())).unwrap_or(&1));
+        }
+
+        *brick = brick.down(brick.get_lowest_z() - min_height);
+
+        for face in brick.get_top_faces() {
+            heightmap
+                .entry((face.x(), face.y()))
+                .and_modify(|h| *h = (*h).max(face.z() + 1))
+                .or_insert(face.z() + 1);
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Vec<Brick> {
+    let mut bricks: Vec<Brick> = vec![];
+    let names = ["A", "B", "C", "D", "E", "F", "G"];
+
+    for (idx, line) in input.lines().enumerate() {
+        let (start, end) = line
+            .split('~')
+            .map(|a| {
+                let (x, y, z) = a
+                    .split(',')
+                    .map(|b| b.parse().unwrap())
+                    .collect_tuple()
+                    .unwrap();
+                Vector::new([x, y, z])
+            })
+            .collect_tuple()
+            .unwrap();
+        bricks.push(Brick {
+            name: names[idx].to_owned(),
+            start,
+            end,
+        });
+    }
+
+    bricks.sort_by_key(Brick::get_lowest_z);
+
+    bricks
+}
@@ -0,0 +1,176 @@
+use aoc_lib::solution::{NoAnswer, Solution};
+use itertools::Itertools;
+
+// const LOWER_LIMIT: f64 =  7.0;
+// const UPPER_LIMIT: f64 = 27.0;
+const LOWER_LIMIT: f64 = 200_000_000_000_000.0;
+const UPPER_LIMIT: f64 = 400_000_000_000_000.0;
+
+#[derive(Debug, Clone)]
+struct LinearPath {
+    vertical_offset: f64,
+    gradient: f64,
+}
+
+#[allow(dead_code)]
+struct Point {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+#[allow(dead_code)]
+struct Velocity {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+pub struct Hailstone {
+    point: Point,
+    velocity: Velocity,
+    path: LinearPath,
+}
+
+#[allow(dead_code)]
+enum Outcome {
+    PastCollisionBoth(Point),
+    PastCollisionA(Point),
+    PastCollisionB(Point),
+    FutureCollisionInTestArea(Point),
+    FutureCollisionOutsideTestArea(Point),
+    NoCollisionPossible,
+}
+
+impl LinearPath {
+    fn calculate_intersection_2d(&self, other: &Self) -> Option<Point> {
+        if self.gradient == other.gradient {
+            // parallel lines
+            return None;
+        };
+
+        // y == m1x + c1
+        // m2x + c2 == m1x + c1
+        // m2x - m1x = c1 - c2
+        // (m2 - m1)x == c1 - c2
+        // x == (c1 - c2)/(m2 - m1)
+        let x = (self.vertical_offset - other.vertical_offset) / (other.gradient - self.gradient);
+
+        // y = mx + c
+        let y = (self.gradient * x) + self.vertical_offset;
+
+        // would be nice to assert here that y is equal in both equations, but cannot compare floats
+
+        Some(Point { x, y, z: 0.0 })
+    }
+}
+
+impl Hailstone {
+    fn is_intersection_in_future(&self, intersection: &Point) -> bool {
+        match (
+            self.velocity.y.is_sign_negative(),
+            intersection.y < self.point.y,
+        ) {
+            (true, true) => true,   // heading toward intersection
+            (true, false) => false, // heading away from intersection
+            (false, true) => false, // heading away from intersection
+            (false, false) => true, // heading toward intersection
+        }
+    }
+
+    fn calculate_intersection_2d(&self, other: &Self) -> Option<Point> {
+        self.path.calculate_intersection_2d(&other.path)
+    }
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const YEAR: usize = 2023;
+    const DAY: usize = 24;
+    const TITLE: &'static str = "Never Tell Me The Odds";
+
+    type Parsed<'a> = Vec<Hailstone>;
+    type Part1 = usize;
+    type Part2 = NoAnswer;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input.lines().map(parse_path).collect()
+    }
+
+    fn part_1(paths: &Self::Parsed<'_>) -> usize {
+        paths
+            .iter()
+            .tuple_combinations::<(&Hailstone, &Hailstone)>()
+            .filter(|(hailstone_a, hailstone_b)| {
+                matches!(
+                    get_outcome(hailstone_a, hailstone_b),
+                    Outcome::FutureCollisionInTestArea(_)
+                )
+            })
+            .count()
+    }
+
+    fn part_2(_paths: &Self::Parsed<'_>) -> NoAnswer {
+        NoAnswer
+    }
+}
+
+fn parse_path(line: &str) -> Hailstone {
+    let (positions, velocities) = line.split(" @ ").collect_tuple().unwrap();
+    let (position_x, position_y, position_z) = positions
+        .trim()
+        .split(", ")
+        .map(|s| s.trim().parse::<f64>().unwrap())
+        .collect_tuple()
+        .unwrap();
+    let (velocity_x, velocity_y, velocity_z) = velocities
+        .trim()
+        .split(", ")
+        .map(|s| s.trim().parse::<f64>().unwrap())
+        .collect_tuple()
+        .unwrap();
+
+    let gradient = velocity_y / velocity_x;
+    let vertical_offset = position_y - (gradient * position_x);
+
+    Hailstone {
+        point: Point {
+            x: position_x,
+            y: position_y,
+            z: position_z,
+        },
+        path: LinearPath {
+            vertical_offset,
+            gradient,
+        },
+        velocity: Velocity {
+            x: velocity_x,
+            y: velocity_y,
+            z: velocity_z,
+        },
+    }
+}
+
+fn get_outcome(hailstone_a: &Hailstone, hailstone_b: &Hailstone) -> Outcome {
+    if let Some(intersection) = hailstone_a.calculate_intersection_2d(hailstone_b) {
+        let hailstone_a_future = hailstone_a.is_intersection_in_future(&intersection);
+        let hailstone_b_future = hailstone_b.is_intersection_in_future(&intersection);
+        match (hailstone_a_future, hailstone_b_future) {
+            (false, false) => return Outcome::PastCollisionBoth(intersection),
+            (true, false) => return Outcome::PastCollisionB(intersection),
+            (false, true) => return Outcome::PastCollisionA(intersection),
+            (true, true) => (),
+        };
+        if intersection.x > UPPER_LIMIT
+            || intersection.x < LOWER_LIMIT
+            || intersection.y > UPPER_LIMIT
+            || intersection.y < LOWER_LIMIT
+        {
+            // outside of test area
+            return Outcome::FutureCollisionOutsideTestArea(intersection);
+        }
+        return Outcome::FutureCollisionInTestArea(intersection);
+    }
+    Outcome::NoCollisionPossible
+}
@@ -0,0 +1,61 @@
+use aoc_lib::solution::Solution;
+use itertools::Itertools;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: usize = 2024;
+    const DAY: usize = 1;
+    const TITLE: &'static str = "Historian Hysteria";
+
+    type Parsed<'a> = (Vec<usize>, Vec<usize>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part_1((left_list, right_list): &Self::Parsed<'_>) -> usize {
+        part_1(left_list, right_list)
+    }
+
+    fn part_2((left_list, right_list): &Self::Parsed<'_>) -> usize {
+        part_2(left_list, right_list)
+    }
+}
+
+fn parse_input(input: &str) -> (Vec<usize>, Vec<usize>) {
+    let (mut left_list, mut right_list): (Vec<_>, Vec<_>) = input
+        .lines()
+        .map(|line| line.split_once("   ").unwrap())
+        .map(|(left_id, right_id)| {
+            (
+                left_id.parse::<usize>().unwrap(),
+                right_id.parse::<usize>().unwrap(),
+            )
+        })
+        .unzip();
+
+    left_list.sort();
+    right_list.sort();
+
+    (left_list, right_list)
+}
+
+// Assumes lists are sorted
+fn part_1(left_list: &[usize], right_list: &[usize]) -> usize {
+    left_list
+        .into_iter()
+        .zip(right_list)
+        .map(|(left, right)| left.abs_diff(*right))
+        .sum::<usize>()
+}
+
+fn part_2(left_list: &[usize], right_list: &[usize]) -> usize {
+    let counts = right_list.into_iter().cloned().counts();
+    left_list
+        .into_iter()
+        .map(|x| x * counts.get(x).unwrap_or(&0))
+        .sum::<usize>()
+}
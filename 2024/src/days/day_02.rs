@@ -0,0 +1,64 @@
+use crate::skip_nth::AocItertools;
+use aoc_lib::solution::Solution;
+use itertools::Itertools;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: usize = 2024;
+    const DAY: usize = 2;
+    const TITLE: &'static str = "Red-Nosed Reports";
+
+    type Parsed<'a> = Vec<Vec<u32>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+            .lines()
+            .map(|line| {
+                line.split_whitespace()
+                    .into_iter()
+                    .map(|y| y.parse::<u32>().unwrap())
+                    .collect_vec()
+            })
+            .collect_vec()
+    }
+
+    fn part_1(reports: &Self::Parsed<'_>) -> usize {
+        part_1(reports)
+    }
+
+    fn part_2(reports: &Self::Parsed<'_>) -> usize {
+        part_2(reports)
+    }
+}
+
+fn part_1(reports: &[Vec<u32>]) -> usize {
+    reports
+        .into_iter()
+        .filter(|x| report_is_safe(x.iter().copied()))
+        .count()
+}
+
+fn part_2(reports: &[Vec<u32>]) -> usize {
+    reports
+        .into_iter()
+        .filter(|report| {
+            report_is_safe(report.iter().copied())
+                || (0..report.len())
+                    .any(|x| report_is_safe(report.into_iter().copied().skip_nth(x)))
+        })
+        .count()
+}
+
+fn report_is_safe(input: impl Iterator<Item = u32>) -> bool {
+    let mut input = input.multipeek();
+    let first = *input.peek().unwrap();
+    let second = *input.peek().unwrap();
+    let ascending = first < second;
+
+    input.into_iter().tuple_windows().all(|(left, right)| {
+        (left < right) == ascending && (1_u32..=3).contains(&left.abs_diff(right))
+    })
+}
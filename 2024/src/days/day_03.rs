@@ -0,0 +1,38 @@
+use aoc_lib::solution::Solution;
+use lazy_regex::regex;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: usize = 2024;
+    const DAY: usize = 3;
+    const TITLE: &'static str = "Mull It Over";
+
+    type Parsed<'a> = &'a str;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part_1(input: &Self::Parsed<'_>) -> u32 {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Parsed<'_>) -> u32 {
+        part_2(input)
+    }
+}
+
+fn part_1(input: &str) -> u32 {
+    regex!(r#"mul\((\d+),(\d+)\)"#)
+        .captures_iter(&input)
+        .map(|x| x[1].parse::<u32>().unwrap() * x[2].parse::<u32>().unwrap())
+        .sum::<u32>()
+}
+
+// just delete everything between don't() and do()
+fn part_2(input: &str) -> u32 {
+    part_1(&regex!(r#"(?s)don't\(\).*?do\(\)"#).replace_all(&input, ""))
+}
@@ -1,7 +1,13 @@
-use aoc_lib::get_input_year;
+pub mod days;
+
+use aoc_lib::{try_get_input_year, AocError};
+
+pub fn try_get_input(day: usize) -> Result<String, AocError> {
+    try_get_input_year(2024, day)
+}
 
 pub fn get_input(day: usize) -> String {
-    get_input_year(2024, day)
+    try_get_input(day).unwrap_or_else(|e| panic!("{e}"))
 }
 
 pub mod skip_nth {
@@ -0,0 +1,104 @@
+use std::{collections::BTreeMap, fs, io, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::workspace_root;
+
+pub const HEADER: &str =
+    "# Answers known to be correct, checked by `aoc verify` and filled in by `aoc verify --record`\n";
+
+/// A year's recorded answers, kept in `<year>/answers.toml` as
+///
+/// ```toml
+/// [day_01]
+/// part_1 = "1034"
+/// part_2 = "1356"
+/// ```
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<String, DayAnswers>);
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part_1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part_2: Option<String>,
+}
+
+impl Answers {
+    pub fn path(year: usize) -> PathBuf {
+        workspace_root().join(year.to_string()).join("answers.toml")
+    }
+
+    /// A year without an answers file has no recorded answers
+    pub fn load(year: usize) -> Result<Self, String> {
+        let path = Self::path(year);
+        match fs::read_to_string(&path) {
+            Ok(contents) => Self::parse(&contents).map_err(|e| format!("{}: {e}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{}: {e}", path.display())),
+        }
+    }
+
+    fn parse(contents: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(contents)
+    }
+
+    pub fn save(&self, year: usize) -> Result<(), String> {
+        let path = Self::path(year);
+        fs::write(&path, self.to_toml()).map_err(|e| format!("{}: {e}", path.display()))
+    }
+
+    fn to_toml(&self) -> String {
+        let answers = toml::to_string(self).expect("Answers are always representable in TOML");
+        format!("{HEADER}\n{answers}")
+    }
+
+    pub fn get(&self, day: usize, part: usize) -> Option<&str> {
+        let answers = self.0.get(&key(day))?;
+        match part {
+            1 => answers.part_1.as_deref(),
+            2 => answers.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, day: usize, part: usize, answer: String) {
+        let answers = self.0.entry(key(day)).or_default();
+        match part {
+            1 => answers.part_1 = Some(answer),
+            2 => answers.part_2 = Some(answer),
+            _ => panic!("Puzzles only have parts 1 and 2, not {part}"),
+        }
+    }
+}
+
+// zero-padded so the file lists days in order
+fn key(day: usize) -> String {
+    format!("day_{day:02}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut answers = Answers::parse(HEADER).unwrap();
+        assert_eq!(answers.get(1, 1), None);
+
+        answers.set(10, 1, "1034".to_string());
+        answers.set(2, 2, "#..#\n.##.".to_string());
+        answers.set(2, 1, "7".to_string());
+
+        let contents = answers.to_toml();
+        assert!(contents.find("[day_02]").unwrap() < contents.find("[day_10]").unwrap());
+
+        let answers = Answers::parse(&contents).unwrap();
+        assert_eq!(answers.get(10, 1), Some("1034"));
+        assert_eq!(answers.get(10, 2), None);
+        assert_eq!(answers.get(2, 1), Some("7"));
+        assert_eq!(answers.get(2, 2), Some("#..#\n.##."));
+    }
+}
@@ -0,0 +1,267 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Duration,
+};
+
+use aoc_lib::{
+    client::AocClient,
+    solution::{print_header, DynSolution},
+};
+use clap::Args;
+use serde::Serialize;
+
+use crate::select;
+
+const STAGES: [&str; 3] = ["parse", "part_1", "part_2"];
+
+#[derive(Args)]
+pub struct BenchArgs {
+    year: Option<usize>,
+    #[arg(requires = "year")]
+    day: Option<usize>,
+    /// How many times to run each day
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+    /// Write every measurement to this file as JSON
+    #[arg(long, value_name = "PATH")]
+    json: Option<PathBuf>,
+    /// Write every measurement to this file as CSV
+    #[arg(long, value_name = "PATH")]
+    csv: Option<PathBuf>,
+    /// Write a markdown table per year to `<DIR>/<year>.md`
+    #[arg(long, value_name = "DIR")]
+    markdown: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Stats {
+    mean: Duration,
+    min: Duration,
+    stddev: Duration,
+}
+
+impl Stats {
+    /// Uses the sample standard deviation, which is zero for a single run
+    fn new(samples: &[Duration]) -> Self {
+        let n = samples.len() as f64;
+        let mean = samples.iter().map(Duration::as_secs_f64).sum::<f64>() / n;
+        let variance = if samples.len() > 1 {
+            samples
+                .iter()
+                .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+                .sum::<f64>()
+                / (n - 1.0)
+        } else {
+            0.0
+        };
+
+        Self {
+            mean: Duration::from_secs_f64(mean),
+            min: *samples.iter().min().expect("There is at least one run"),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+impl std::fmt::Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:.2?} ± {:.2?} (min {:.2?})",
+            self.mean, self.stddev, self.min
+        )
+    }
+}
+
+struct DayReport {
+    solution: &'static dyn DynSolution,
+    stages: [Stats; 3],
+}
+
+/// One row of the JSON and CSV reports
+#[derive(Serialize)]
+struct Record {
+    year: usize,
+    day: usize,
+    stage: &'static str,
+    runs: u32,
+    mean_ns: u128,
+    min_ns: u128,
+    stddev_ns: u128,
+}
+
+pub fn bench(args: BenchArgs) -> ExitCode {
+    let Some(solutions) = select(args.year, args.day) else {
+        return ExitCode::FAILURE;
+    };
+
+    let client = AocClient::from_env();
+    let mut reports = vec![];
+    let mut failures = 0;
+
+    for solution in solutions {
+        print_header(solution);
+        let input = match client.input(solution.year(), solution.day()) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("error: {e}");
+                failures += 1;
+                continue;
+            }
+        };
+
+        let Some(stages) = time(solution, &input, args.runs) else {
+            eprintln!("error: panicked");
+            failures += 1;
+            continue;
+        };
+        for (stage, stats) in STAGES.iter().zip(&stages) {
+            println!("{stage:<8}{stats}");
+        }
+        reports.push(DayReport { solution, stages });
+    }
+
+    let written = [
+        args.json
+            .as_deref()
+            .map(|path| (path, json(&reports, args.runs))),
+        args.csv
+            .as_deref()
+            .map(|path| (path, csv(&reports, args.runs))),
+    ];
+    for (path, contents) in written.into_iter().flatten() {
+        if let Err(e) = fs::write(path, contents) {
+            eprintln!("error: {}: {e}", path.display());
+            failures += 1;
+        }
+    }
+    if let Some(dir) = &args.markdown {
+        if let Err(e) = write_markdown(dir, &reports, args.runs) {
+            eprintln!("error: {}: {e}", dir.display());
+            failures += 1;
+        }
+    }
+
+    if failures > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Times every stage over the given number of runs, or `None` if the day panics
+fn time(solution: &dyn DynSolution, input: &str, runs: u32) -> Option<[Stats; 3]> {
+    let mut samples: [Vec<Duration>; 3] = Default::default();
+    for _ in 0..runs {
+        let timings = panic::catch_unwind(AssertUnwindSafe(|| solution.time(input))).ok()?;
+        for (samples, timing) in samples.iter_mut().zip(timings) {
+            samples.push(timing);
+        }
+    }
+    Some(samples.map(|samples| Stats::new(&samples)))
+}
+
+fn records(reports: &[DayReport], runs: u32) -> impl Iterator<Item = Record> + '_ {
+    reports.iter().flat_map(move |report| {
+        STAGES
+            .iter()
+            .zip(report.stages)
+            .map(move |(stage, stats)| Record {
+                year: report.solution.year(),
+                day: report.solution.day(),
+                stage,
+                runs,
+                mean_ns: stats.mean.as_nanos(),
+                min_ns: stats.min.as_nanos(),
+                stddev_ns: stats.stddev.as_nanos(),
+            })
+    })
+}
+
+fn json(reports: &[DayReport], runs: u32) -> String {
+    let records = records(reports, runs).collect::<Vec<_>>();
+    serde_json::to_string_pretty(&records).expect("Records are always representable in JSON")
+}
+
+fn csv(reports: &[DayReport], runs: u32) -> String {
+    let mut csv = "year,day,stage,runs,mean_ns,min_ns,stddev_ns\n".to_string();
+    for record in records(reports, runs) {
+        let Record {
+            year,
+            day,
+            stage,
+            runs,
+            mean_ns,
+            min_ns,
+            stddev_ns,
+        } = record;
+        writeln!(
+            csv,
+            "{year},{day},{stage},{runs},{mean_ns},{min_ns},{stddev_ns}"
+        )
+        .unwrap();
+    }
+    csv
+}
+
+fn write_markdown(dir: &Path, reports: &[DayReport], runs: u32) -> std::io::Result<()> {
+    let mut years = BTreeMap::<usize, Vec<&DayReport>>::new();
+    for report in reports {
+        years
+            .entry(report.solution.year())
+            .or_default()
+            .push(report);
+    }
+
+    fs::create_dir_all(dir)?;
+    for (year, reports) in years {
+        fs::write(
+            dir.join(format!("{year}.md")),
+            markdown(year, &reports, runs),
+        )?;
+    }
+    Ok(())
+}
+
+fn markdown(year: usize, reports: &[&DayReport], runs: u32) -> String {
+    let mut table = format!(
+        "# {year}\n\nMean ± standard deviation (minimum) over {runs} runs.\n\n\
+         | Day | Title | Parse | Part 1 | Part 2 |\n\
+         |----:|-------|------:|-------:|-------:|\n"
+    );
+    for report in reports {
+        let [parse, part_1, part_2] = report.stages;
+        writeln!(
+            table,
+            "| {} | {} | {parse} | {part_1} | {part_2} |",
+            report.solution.day(),
+            report.solution.title(),
+        )
+        .unwrap();
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = [2, 4, 4, 4, 5, 5, 7, 9].map(Duration::from_millis);
+        let stats = Stats::new(&samples);
+
+        assert_eq!(stats.mean, Duration::from_millis(5));
+        assert_eq!(stats.min, Duration::from_millis(2));
+        // sample variance is 32 / 7
+        assert_eq!(stats.stddev.as_micros(), 2138);
+
+        let single = Stats::new(&[Duration::from_millis(3)]);
+        assert_eq!(single.stddev, Duration::ZERO);
+    }
+}
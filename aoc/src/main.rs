@@ -0,0 +1,167 @@
+mod answers;
+mod bench;
+mod registry;
+mod scaffold;
+mod verify;
+
+use std::{
+    fs, io,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use aoc_lib::{
+    client::AocClient,
+    solution::{print_answer, print_header, DynSolution},
+};
+use clap::{Args, Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(about = "Advent of Code solutions for every year in this workspace")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a day, a whole year, or everything
+    Run(RunArgs),
+    /// Check cached inputs against the answers recorded in each year's `answers.toml`
+    Verify(verify::VerifyArgs),
+    /// Time each day's parsing and parts over repeated runs
+    Bench(bench::BenchArgs),
+    /// Add a day to a year's crate, creating the crate if needed, and fetch its input
+    New(scaffold::NewArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[arg(required_unless_present = "all")]
+    year: Option<usize>,
+    day: Option<usize>,
+    /// Only solve this part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Read the input from this file instead of fetching it; `-` reads stdin
+    #[arg(long, requires = "day")]
+    input: Option<PathBuf>,
+    /// Solve every day of every year
+    #[arg(long, conflicts_with_all = ["year", "day"])]
+    all: bool,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify::verify(args),
+        Command::Bench(args) => bench::bench(args),
+        Command::New(args) => scaffold::new(args),
+    }
+}
+
+fn run(args: RunArgs) -> ExitCode {
+    let Some(solutions) = select(args.year, args.day) else {
+        return ExitCode::FAILURE;
+    };
+
+    let parts = match args.part {
+        Some(part) => vec![part as usize],
+        None => vec![1, 2],
+    };
+    let client = AocClient::from_env();
+    let mut failures = 0;
+
+    for solution in solutions {
+        let input = match &args.input {
+            Some(path) => read_input(path).map_err(|e| format!("{}: {e}", path.display())),
+            None => client
+                .input(solution.year(), solution.day())
+                .map_err(|e| e.to_string()),
+        };
+
+        print_header(solution);
+        let input = match input {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("error: {e}");
+                failures += 1;
+                continue;
+            }
+        };
+
+        if !solve(solution, &input, &parts, &mut print_answer) {
+            failures += 1;
+        }
+    }
+
+    if failures > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Where the year crates live; the binary is meant to be run from a checkout
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("The runner lives inside the workspace")
+        .to_path_buf()
+}
+
+/// The days matching the given year and day, if any; reports when there are none
+fn select(year: Option<usize>, day: Option<usize>) -> Option<Vec<&'static dyn DynSolution>> {
+    let solutions = registry::solutions()
+        .filter(|s| year.is_none_or(|year| s.year() == year))
+        .filter(|s| day.is_none_or(|day| s.day() == day))
+        .collect::<Vec<_>>();
+
+    if solutions.is_empty() {
+        match (year, day) {
+            (Some(year), Some(day)) => eprintln!("error: no solution for {year} day {day}"),
+            (Some(year), None) => eprintln!("error: no solutions for {year}"),
+            _ => eprintln!("error: no solutions"),
+        }
+        return None;
+    }
+    Some(solutions)
+}
+
+/// Solves the given parts like [`DynSolution::solve`], but reports a panicking part instead of
+/// unwinding, so one broken day doesn't stop the rest. Returns whether every part finished.
+fn solve(
+    solution: &dyn DynSolution,
+    input: &str,
+    parts: &[usize],
+    on_answer: &mut dyn FnMut(usize, Option<String>),
+) -> bool {
+    let mut solved = 0;
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        solution.solve(input, parts, &mut |part, answer| {
+            on_answer(part, answer);
+            solved += 1;
+        })
+    }));
+
+    if result.is_err() {
+        match parts.get(solved) {
+            Some(part) => eprintln!("error: part {part} panicked"),
+            None => eprintln!("error: panicked"),
+        }
+        return false;
+    }
+    true
+}
+
+/// Reads an input the way the client hands them out, without surrounding whitespace
+fn read_input(path: &Path) -> io::Result<String> {
+    let input = if path == Path::new("-") {
+        io::read_to_string(io::stdin())?
+    } else {
+        fs::read_to_string(path)?
+    };
+    Ok(input.trim().to_string())
+}
@@ -0,0 +1,18 @@
+use aoc_lib::solution::DynSolution;
+
+/// Every day that has a solution, in year then day order
+pub fn solutions() -> impl Iterator<Item = &'static dyn DynSolution> {
+    [
+        aoc_2015::days::DAYS,
+        aoc_2016::days::DAYS,
+        aoc_2017::days::DAYS,
+        aoc_2018::days::DAYS,
+        aoc_2019::days::DAYS,
+        aoc_2020::days::DAYS,
+        aoc_2023::days::DAYS,
+        aoc_2024::days::DAYS,
+    ]
+    .into_iter()
+    .flatten()
+    .copied()
+}
@@ -0,0 +1,346 @@
+use std::{
+    collections::BTreeSet,
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+    process::{Command, ExitCode},
+};
+
+use aoc_lib::{client::AocClient, parse_examples, parse_title, Example};
+use clap::Args;
+
+use crate::{answers, workspace_root};
+
+#[derive(Args)]
+pub struct NewArgs {
+    year: usize,
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+pub fn new(args: NewArgs) -> ExitCode {
+    match scaffold(args.year, args.day as usize) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn scaffold(year: usize, day: usize) -> Result<(), String> {
+    let root = workspace_root();
+    let crate_dir = root.join(year.to_string());
+    let day_path = crate_dir
+        .join("src")
+        .join("days")
+        .join(format!("day_{day:02}.rs"));
+    if day_path.exists() {
+        return Err(format!("{} already exists", day_path.display()));
+    }
+
+    let mut created = vec![];
+    if !crate_dir.exists() {
+        created.extend(create_year(&root, year)?);
+    }
+
+    // the files are still worth having without the puzzle, e.g. before it unlocks
+    let client = AocClient::from_env();
+    let (title, examples) = match client.puzzle(year, day) {
+        Ok(html) => (parse_title(&html), parse_examples(&html)),
+        Err(e) => {
+            eprintln!("warning: couldn't fetch the puzzle: {e}");
+            (None, vec![])
+        }
+    };
+    if let Err(e) = client.input(year, day) {
+        eprintln!("warning: couldn't fetch the input: {e}");
+    }
+
+    let title = title.unwrap_or_else(|| "TODO".to_string());
+    let day_file = fill(include_str!("../templates/day.rs.tmpl"), year, day)
+        .replace("{title}", &format!("{title:?}"))
+        + &example_tests(day, &examples);
+    let bin_file = fill(include_str!("../templates/bin.rs.tmpl"), year, day);
+    let [day_path, bin_path, days_path] = add_day(&crate_dir, day, &day_file, &bin_file)?;
+    created.extend([day_path, bin_path]);
+
+    let sources = created
+        .iter()
+        .chain([&days_path])
+        .filter(|path| path.extension() == Some("rs".as_ref()));
+    // only tidies up, so it's fine if rustfmt isn't around
+    let _ = Command::new("rustfmt")
+        .args(["--edition", "2021"])
+        .args(sources)
+        .status();
+
+    for path in created {
+        println!("created {}", path.display());
+    }
+    println!(
+        "{year} day {day}: {title}, with {} example(s)",
+        examples.len()
+    );
+    Ok(())
+}
+
+/// Sets up the `aoc-<year>` crate and adds it to the workspace and the registry
+fn create_year(root: &Path, year: usize) -> Result<Vec<PathBuf>, String> {
+    let crate_dir = root.join(year.to_string());
+    let files = [
+        (
+            crate_dir.join("Cargo.toml"),
+            fill(include_str!("../templates/Cargo.toml.tmpl"), year, 0),
+        ),
+        (
+            crate_dir.join("src").join("lib.rs"),
+            fill(include_str!("../templates/lib.rs.tmpl"), year, 0),
+        ),
+        (
+            crate_dir.join("src").join("days").join("mod.rs"),
+            days_mod(&BTreeSet::new()),
+        ),
+        (crate_dir.join("answers.toml"), answers::HEADER.to_string()),
+    ];
+    for (path, contents) in &files {
+        write(path, contents)?;
+    }
+
+    insert_sorted(
+        &root.join("Cargo.toml"),
+        &format!("    \"{year}\","),
+        |line| {
+            line.strip_prefix("    \"")
+                .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_digit()))
+        },
+    )?;
+    insert_sorted(
+        &root.join("aoc").join("Cargo.toml"),
+        &format!("aoc-{year} = {{ version = \"0.1.0\", path = \"../{year}\" }}"),
+        |line| line.starts_with("aoc-"),
+    )?;
+    insert_sorted(
+        &root.join("aoc").join("src").join("registry.rs"),
+        &format!("        aoc_{year}::days::DAYS,"),
+        |line| line.trim_start().starts_with("aoc_") && line.ends_with("::days::DAYS,"),
+    )?;
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+/// Writes the day's module and binary, and declares the module in `days/mod.rs`.
+///
+/// Nothing is written unless `days/mod.rs` can be updated, see [`days_with`].
+fn add_day(
+    crate_dir: &Path,
+    day: usize,
+    day_file: &str,
+    bin_file: &str,
+) -> Result<[PathBuf; 3], String> {
+    let (days_path, days_file) = days_with(crate_dir, day)?;
+    let src = crate_dir.join("src");
+    let day_path = src.join("days").join(format!("day_{day:02}.rs"));
+    let bin_path = src.join("bin").join(format!("day_{day:02}.rs"));
+    write(&day_path, day_file)?;
+    write(&bin_path, bin_file)?;
+    write(&days_path, &days_file)?;
+    Ok([day_path, bin_path, days_path])
+}
+
+/// The path of `days/mod.rs` and its contents with the day's module declared and listed in
+/// `DAYS`.
+///
+/// The file is regenerated as a whole, so this refuses to touch one that has been changed
+/// beyond the list of days.
+fn days_with(crate_dir: &Path, day: usize) -> Result<(PathBuf, String), String> {
+    let path = crate_dir.join("src").join("days").join("mod.rs");
+    let contents = read(&path)?;
+    let mut days = contents
+        .lines()
+        .filter_map(|line| line.strip_prefix("pub mod day_")?.strip_suffix(';'))
+        .map(|day| day.parse::<usize>())
+        .collect::<Result<BTreeSet<_>, _>>()
+        .map_err(|e| format!("{}: {e}", path.display()))?;
+
+    // rustfmt may have put `DAYS` on one line, so compare without any layout
+    let squash = |s: &str| s.split_whitespace().collect::<String>().replace(",]", "]");
+    if squash(&contents) != squash(&days_mod(&days)) {
+        return Err(format!(
+            "{} has been edited by hand, so add day_{day:02} to it yourself",
+            path.display()
+        ));
+    }
+
+    days.insert(day);
+    let contents = days_mod(&days);
+    Ok((path, contents))
+}
+
+fn days_mod(days: &BTreeSet<usize>) -> String {
+    let mut contents = String::new();
+    for day in days {
+        writeln!(contents, "pub mod day_{day:02};").unwrap();
+    }
+    if !days.is_empty() {
+        contents.push('\n');
+    }
+    contents.push_str("use aoc_lib::solution::DynSolution;\n\n");
+    contents.push_str("pub static DAYS: &[&dyn DynSolution] = &[\n");
+    for day in days {
+        writeln!(contents, "    &day_{day:02}::Day{day:02},").unwrap();
+    }
+    contents.push_str("];\n");
+    contents
+}
+
+/// A test per example from the puzzle text that comes with an answer
+fn example_tests(day: usize, examples: &[Example]) -> String {
+    let examples = examples
+        .iter()
+        .filter(|e| e.expected_part_1.is_some() || e.expected_part_2.is_some())
+        .collect::<Vec<_>>();
+    if examples.is_empty() {
+        return String::new();
+    }
+
+    let mut tests =
+        "\n#[cfg(test)]\nmod tests {\n    use aoc_lib::solution::Answer;\n\n    use super::*;\n"
+            .to_string();
+    for (i, example) in examples.iter().enumerate() {
+        let input = raw_string(&example.input);
+        write!(tests, "\n    const EXAMPLE_{}: &str = {input};\n", i + 1).unwrap();
+    }
+    for (i, example) in examples.iter().enumerate() {
+        let n = i + 1;
+        write!(
+            tests,
+            "\n    #[test]\n    fn test_example_{n}() {{\n        let parsed = Day{day:02}::parse(EXAMPLE_{n});\n"
+        )
+        .unwrap();
+        for (part, expected) in [(1, &example.expected_part_1), (2, &example.expected_part_2)] {
+            if let Some(expected) = expected {
+                writeln!(
+                    tests,
+                    "        assert_eq!(Day{day:02}::part_{part}(&parsed).to_answer().as_deref(), Some({expected:?}));"
+                )
+                .unwrap();
+            }
+        }
+        tests.push_str("    }\n");
+    }
+    tests.push_str("}\n");
+    tests
+}
+
+/// A raw string literal with as few `#`s as the contents allow
+fn raw_string(contents: &str) -> String {
+    let hashes = (0..)
+        .map(|n| "#".repeat(n))
+        .find(|hashes| !contents.contains(&format!("\"{hashes}")))
+        .unwrap();
+    format!("r{hashes}\"{contents}\"{hashes}")
+}
+
+fn fill(template: &str, year: usize, day: usize) -> String {
+    template
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string())
+        .replace("{nn}", &format!("{day:02}"))
+}
+
+/// Adds a line among its peers, keeping them in order
+fn insert_sorted(path: &Path, line: &str, is_peer: impl Fn(&str) -> bool) -> Result<(), String> {
+    let contents = read(path)?;
+    let mut lines = contents.lines().collect::<Vec<_>>();
+    let peers = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| is_peer(l))
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+    let Some(&last) = peers.last() else {
+        return Err(format!(
+            "{}: don't know where to add {line:?}",
+            path.display()
+        ));
+    };
+
+    let index = peers
+        .iter()
+        .find(|&&i| lines[i] > line)
+        .map_or(last + 1, |&i| i);
+    lines.insert(index, line);
+    write(path, &(lines.join("\n") + "\n"))
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("{}: {e}", parent.display()))?;
+    }
+    fs::write(path, contents).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_raw_string() {
+        assert_eq!(raw_string("a<b"), r#"r"a<b""#);
+        assert_eq!(raw_string("say \"hi\""), r##"r#"say "hi""#"##);
+        assert_eq!(raw_string("\"#"), r###"r##""#"##"###);
+    }
+
+    #[test]
+    fn test_add_day() {
+        let crate_dir = std::env::temp_dir().join(format!("aoc_scaffold_{}", std::process::id()));
+        let days_path = crate_dir.join("src").join("days").join("mod.rs");
+        write(&days_path, &days_mod(&BTreeSet::from([1]))).unwrap();
+        let files = |dir: &Path| {
+            let mut files = vec![];
+            let mut dirs = vec![dir.to_path_buf()];
+            while let Some(dir) = dirs.pop() {
+                for entry in fs::read_dir(dir).unwrap() {
+                    let path = entry.unwrap().path();
+                    if path.is_dir() {
+                        dirs.push(path.clone());
+                    }
+                    files.push(path);
+                }
+            }
+            files.sort();
+            files
+        };
+
+        let added = add_day(&crate_dir, 2, "// day", "// bin").unwrap();
+        assert!(added.iter().all(|path| path.exists()));
+        assert_eq!(read(&days_path).unwrap(), days_mod(&BTreeSet::from([1, 2])));
+
+        // a hand-edited list of days stops it before it writes anything
+        let edited = read(&days_path).unwrap() + "\nmod helpers;\n";
+        write(&days_path, &edited).unwrap();
+        let before = files(&crate_dir);
+        assert!(add_day(&crate_dir, 3, "// day", "// bin")
+            .unwrap_err()
+            .contains("edited by hand"));
+        assert_eq!(files(&crate_dir), before);
+        assert_eq!(read(&days_path).unwrap(), edited);
+
+        fs::remove_dir_all(crate_dir).unwrap();
+    }
+
+    #[test]
+    fn test_days_mod() {
+        let days = BTreeSet::from([1, 2]);
+        assert_eq!(
+            days_mod(&days),
+            "pub mod day_01;\npub mod day_02;\n\nuse aoc_lib::solution::DynSolution;\n\n\
+             pub static DAYS: &[&dyn DynSolution] = &[\n    &day_01::Day01,\n    &day_02::Day02,\n];\n"
+        );
+    }
+}
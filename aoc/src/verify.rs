@@ -0,0 +1,126 @@
+use std::{
+    collections::{btree_map::Entry, BTreeMap, BTreeSet},
+    process::ExitCode,
+};
+
+use aoc_lib::client::AocClient;
+use clap::Args;
+
+use crate::{answers::Answers, select, solve};
+
+#[derive(Args)]
+pub struct VerifyArgs {
+    year: Option<usize>,
+    #[arg(requires = "year")]
+    day: Option<usize>,
+    /// Record the current answer for parts that don't have one yet
+    #[arg(long)]
+    record: bool,
+}
+
+#[derive(Default)]
+struct Tally {
+    passed: usize,
+    failed: usize,
+    missing: usize,
+    skipped: usize,
+}
+
+pub fn verify(args: VerifyArgs) -> ExitCode {
+    let Some(solutions) = select(args.year, args.day) else {
+        return ExitCode::FAILURE;
+    };
+
+    // only ever the cached inputs, so checking every year doesn't hammer the site
+    let client = AocClient::from_env().with_offline(true);
+    let mut answers = BTreeMap::new();
+    let mut recorded_years = BTreeSet::new();
+    let mut tally = Tally::default();
+
+    for solution in solutions {
+        let (year, day) = (solution.year(), solution.day());
+        let recorded = match answers.entry(year) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => match Answers::load(year) {
+                Ok(loaded) => entry.insert(loaded),
+                Err(e) => {
+                    eprintln!("error: {e}");
+                    return ExitCode::FAILURE;
+                }
+            },
+        };
+
+        let input = match client.input(year, day) {
+            Ok(input) => input,
+            Err(e) => {
+                println!("{year} day {day}: skipped, {e}");
+                tally.skipped += 1;
+                continue;
+            }
+        };
+
+        let mut results = vec![];
+        let finished = solve(solution, &input, &[1, 2], &mut |part, answer| {
+            results.push((part, answer))
+        });
+
+        for (part, answer) in &results {
+            let name = format!("{year} day {day} part {part}");
+            match (recorded.get(day, *part), answer) {
+                (Some(expected), Some(answer)) if expected == answer => {
+                    println!("{name}: pass");
+                    tally.passed += 1;
+                }
+                (Some(expected), Some(answer)) => {
+                    println!("{name}: FAIL, expected {expected:?} but got {answer:?}");
+                    tally.failed += 1;
+                }
+                (Some(expected), None) => {
+                    println!("{name}: FAIL, expected {expected:?} but got no answer");
+                    tally.failed += 1;
+                }
+                (None, Some(answer)) if args.record => {
+                    println!("{name}: recorded {answer:?}");
+                    recorded.set(day, *part, answer.clone());
+                    recorded_years.insert(year);
+                    tally.missing += 1;
+                }
+                (None, Some(answer)) => {
+                    println!("{name}: missing, got {answer:?}");
+                    tally.missing += 1;
+                }
+                // a part without a puzzle of its own
+                (None, None) => {}
+            }
+        }
+        if !finished {
+            println!(
+                "{year} day {day} part {}: FAIL, panicked",
+                results.len() + 1
+            );
+            tally.failed += 1;
+        }
+    }
+
+    for year in recorded_years {
+        if let Err(e) = answers[&year].save(year) {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+    }
+
+    println!(
+        "{} passed, {} failed, {} {}, {} skipped",
+        tally.passed,
+        tally.failed,
+        tally.missing,
+        if args.record { "recorded" } else { "missing" },
+        tally.skipped
+    );
+
+    if tally.failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,361 @@
+use crate::cycle::Cycle;
+use crate::grid::DenseGrid;
+use crate::vector::{Neighbourhood, Vector};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// The usual rule for cells that are either live or dead: a dead cell comes to life with one of
+/// the `born` counts of live neighbours, and a live one survives with one of the `survive` counts.
+///
+/// Conway's Game of Life is `life(&[3], &[2, 3])`.
+pub fn life<'a>(born: &'a [usize], survive: &'a [usize]) -> impl Fn(bool, &[bool]) -> bool + 'a {
+    move |live, neighbours| {
+        let count = neighbours.iter().filter(|x| **x).count();
+        if live {
+            survive.contains(&count)
+        } else {
+            born.contains(&count)
+        }
+    }
+}
+
+/// An unbounded world of live and dead cells, of which only the live ones are stored.
+///
+/// Each step only looks at the live cells and their neighbours, so a dead cell without live
+/// neighbours always stays dead, and the neighbourhood has to be symmetric: a cell that has a
+/// live neighbour is also one of that neighbour's neighbours.
+#[derive(Clone, Debug)]
+pub struct ActiveSet<const DIMENSIONS: usize> {
+    live: HashSet<Vector<DIMENSIONS>>,
+    neighbourhood: Neighbourhood<DIMENSIONS>,
+    stuck: HashSet<Vector<DIMENSIONS>>,
+}
+
+impl<const DIMENSIONS: usize> ActiveSet<DIMENSIONS> {
+    pub fn new(
+        live: impl IntoIterator<Item = Vector<DIMENSIONS>>,
+        neighbourhood: Neighbourhood<DIMENSIONS>,
+    ) -> Self {
+        Self {
+            live: live.into_iter().collect(),
+            neighbourhood,
+            stuck: HashSet::new(),
+        }
+    }
+
+    /// Cells that are live whatever the rule says
+    pub fn with_stuck(mut self, stuck: impl IntoIterator<Item = Vector<DIMENSIONS>>) -> Self {
+        self.stuck.extend(stuck);
+        self.live.extend(&self.stuck);
+        self
+    }
+
+    pub fn live(&self) -> &HashSet<Vector<DIMENSIONS>> {
+        &self.live
+    }
+
+    pub fn is_live(&self, point: Vector<DIMENSIONS>) -> bool {
+        self.live.contains(&point)
+    }
+
+    pub fn len(&self) -> usize {
+        self.live.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.live.is_empty()
+    }
+
+    /// Updates every cell at once to `rule(live, neighbours)`, where `neighbours` says whether
+    /// each neighbour is live, in the order the neighbourhood lists them
+    pub fn step(&mut self, rule: impl Fn(bool, &[bool]) -> bool) {
+        let candidates = self
+            .live
+            .iter()
+            .flat_map(|point| self.neighbourhood.of(*point))
+            .chain(self.live.iter().copied())
+            .collect::<HashSet<_>>();
+
+        let mut neighbours = vec![];
+        let next = candidates
+            .into_iter()
+            .filter(|point| {
+                neighbours.clear();
+                neighbours.extend(
+                    self.neighbourhood
+                        .of(*point)
+                        .map(|neighbour| self.live.contains(&neighbour)),
+                );
+                rule(self.live.contains(point), &neighbours)
+            })
+            .chain(self.stuck.iter().copied())
+            .collect();
+        self.live = next;
+    }
+
+    pub fn run(&mut self, steps: usize, rule: impl Fn(bool, &[bool]) -> bool) {
+        for _ in 0..steps {
+            self.step(&rule);
+        }
+    }
+
+    /// Steps until the world is in a state it has been in before, which leaves it at
+    /// `start + length` steps. Never returns for a world that keeps growing.
+    pub fn run_until_repeat(&mut self, rule: impl Fn(bool, &[bool]) -> bool) -> Cycle {
+        let mut seen = HashMap::new();
+        for steps in 0.. {
+            if let Some(start) = seen.insert(self.state(), steps) {
+                return Cycle {
+                    start,
+                    length: steps - start,
+                };
+            }
+            self.step(&rule);
+        }
+        unreachable!()
+    }
+
+    // sets don't hash, so the live cells in order stand in for them
+    fn state(&self) -> Vec<[i32; DIMENSIONS]> {
+        let mut state = self.live.iter().map(|point| **point).collect::<Vec<_>>();
+        state.sort_unstable();
+        state
+    }
+}
+
+/// A world of a fixed size whose cells can be in any state. Cells past the edge don't exist, so
+/// the ones along it have fewer neighbours.
+#[derive(Clone, Debug)]
+pub struct Bounded<T> {
+    grid: DenseGrid<T>,
+    neighbours: DenseGrid<Vec<Vector<2>>>,
+    stuck: HashSet<Vector<2>>,
+}
+
+impl<T> Bounded<T> {
+    pub fn new(grid: DenseGrid<T>, neighbourhood: Neighbourhood) -> Self {
+        let neighbours = DenseGrid::from_fn(grid.width(), grid.height(), |point| {
+            neighbourhood
+                .of(point)
+                .filter(|neighbour| grid.contains(*neighbour))
+                .collect()
+        });
+        Self::with_neighbours(grid, neighbours)
+    }
+
+    /// A cell's neighbours are the first cells it can see in each of the eight directions,
+    /// looking past any that aren't `visible`.
+    ///
+    /// The view is worked out once from the starting grid, so cells that can be looked past
+    /// shouldn't change.
+    pub fn line_of_sight(grid: DenseGrid<T>, visible: impl Fn(&T) -> bool) -> Self {
+        let neighbours = DenseGrid::from_fn(grid.width(), grid.height(), |point| {
+            Vector::default()
+                .neighbours_diagonals()
+                .filter_map(|direction| {
+                    let mut cursor = point + direction;
+                    while !visible(grid.get(cursor)?) {
+                        cursor += direction;
+                    }
+                    Some(cursor)
+                })
+                .collect()
+        });
+        Self::with_neighbours(grid, neighbours)
+    }
+
+    fn with_neighbours(grid: DenseGrid<T>, neighbours: DenseGrid<Vec<Vector<2>>>) -> Self {
+        Self {
+            grid,
+            neighbours,
+            stuck: HashSet::new(),
+        }
+    }
+
+    /// Cells set to the given states, which they keep whatever the rule says
+    pub fn with_stuck(mut self, stuck: impl IntoIterator<Item = (Vector<2>, T)>) -> Self {
+        for (point, state) in stuck {
+            self.grid.insert(point, state);
+            self.stuck.insert(point);
+        }
+        self
+    }
+
+    pub fn grid(&self) -> &DenseGrid<T> {
+        &self.grid
+    }
+
+    pub fn into_grid(self) -> DenseGrid<T> {
+        self.grid
+    }
+}
+
+impl<T: Clone + PartialEq> Bounded<T> {
+    /// Updates every cell at once to `rule(cell, neighbours)`, returning whether any changed
+    pub fn step(&mut self, rule: impl Fn(&T, &[&T]) -> T) -> bool {
+        let mut neighbours = vec![];
+        let next = DenseGrid::from_fn(self.grid.width(), self.grid.height(), |point| {
+            let cell = &self.grid[point];
+            if self.stuck.contains(&point) {
+                return cell.clone();
+            }
+            neighbours.clear();
+            neighbours.extend(
+                self.neighbours[point]
+                    .iter()
+                    .map(|neighbour| &self.grid[*neighbour]),
+            );
+            rule(cell, &neighbours)
+        });
+        let changed = next != self.grid;
+        self.grid = next;
+        changed
+    }
+
+    pub fn run(&mut self, steps: usize, rule: impl Fn(&T, &[&T]) -> T) {
+        for _ in 0..steps {
+            self.step(&rule);
+        }
+    }
+
+    /// See [`ActiveSet::run_until_repeat`]
+    pub fn run_until_repeat(&mut self, rule: impl Fn(&T, &[&T]) -> T) -> Cycle
+    where
+        T: Eq + Hash,
+    {
+        let mut seen = HashMap::new();
+        for steps in 0.. {
+            if let Some(start) = seen.insert(self.grid.clone(), steps) {
+                return Cycle {
+                    start,
+                    length: steps - start,
+                };
+            }
+            self.step(&rule);
+        }
+        unreachable!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> DenseGrid<bool> {
+        DenseGrid::parse(input, |c| c == '#')
+    }
+
+    fn points(grid: &DenseGrid<bool>) -> impl Iterator<Item = Vector<2>> + '_ {
+        grid.iter()
+            .filter(|(_, live)| **live)
+            .map(|(point, _)| point)
+    }
+
+    #[test]
+    fn test_active_set() {
+        let conway = life(&[3], &[2, 3]);
+
+        let mut blinker = ActiveSet::new(points(&parse("###")), Neighbourhood::Moore);
+        let repeat = blinker.run_until_repeat(&conway);
+        assert_eq!(
+            repeat,
+            Cycle {
+                start: 0,
+                length: 2
+            }
+        );
+        assert!(!repeat.is_steady());
+
+        // a glider is back in shape every 4 steps, one cell further along each axis
+        let glider = parse(".#.\n..#\n###");
+        let mut world = ActiveSet::new(points(&glider), Neighbourhood::Moore);
+        world.run(4, &conway);
+        let moved = points(&glider)
+            .map(|point| point + Vector::new([1, 1]))
+            .collect::<HashSet<_>>();
+        assert_eq!(world.live(), &moved);
+
+        let mut cube = ActiveSet::new(
+            [Vector::new([0, 0, 0]), Vector::new([5, 5, 5])],
+            Neighbourhood::VonNeumann,
+        );
+        cube.step(life(&[1], &[0]));
+        assert_eq!(cube.len(), 14);
+
+        // rule 90, where a cell is live when exactly one of the cells either side of it was
+        let mut row = ActiveSet::new([Vector::new([0])], Neighbourhood::VonNeumann);
+        row.run(2, |_, neighbours| neighbours[0] != neighbours[1]);
+        let mut live = row.live().iter().map(|point| point[0]).collect::<Vec<_>>();
+        live.sort_unstable();
+        assert_eq!(live, [-2, 2]);
+
+        // the order of the neighbours tells them apart, so a pattern can just move along
+        let mut glider = ActiveSet::new([Vector::new([0])], Neighbourhood::Within(2));
+        glider.run(3, |_, neighbours| neighbours == [false, true, false, false]);
+        assert_eq!(glider.live(), &HashSet::from([Vector::new([3])]));
+
+        let mut stuck = ActiveSet::new([], Neighbourhood::Moore).with_stuck([Vector::new([0])]);
+        stuck.run(3, &conway);
+        assert!(stuck.is_live(Vector::new([0])));
+        assert_eq!(stuck.len(), 1);
+    }
+
+    #[test]
+    fn test_bounded() {
+        let conway = |live: &bool, neighbours: &[&bool]| {
+            let neighbours = neighbours.iter().map(|n| **n).collect::<Vec<_>>();
+            life(&[3], &[2, 3])(*live, &neighbours)
+        };
+
+        // a block is stable straight away, while a blinker cut by the edge dies out
+        let mut world = Bounded::new(
+            parse("##....\n##....\n......\n......\n...###"),
+            Neighbourhood::Moore,
+        );
+        assert_eq!(
+            world.run_until_repeat(conway),
+            Cycle {
+                start: 2,
+                length: 1
+            }
+        );
+        assert_eq!(
+            world.grid(),
+            &parse("##....\n##....\n......\n......\n......")
+        );
+
+        // a whole blinker never settles, but it does repeat
+        let mut world = Bounded::new(
+            parse(".....\n..#..\n..#..\n..#..\n....."),
+            Neighbourhood::Moore,
+        );
+        assert_eq!(
+            world.run_until_repeat(conway),
+            Cycle {
+                start: 0,
+                length: 2
+            }
+        );
+
+        let mut world = Bounded::new(parse("...\n...\n..#"), Neighbourhood::Moore)
+            .with_stuck([(Vector::new([0, 0]), true)]);
+        world.run(2, conway);
+        assert_eq!(world.into_grid(), parse("#..\n...\n..."));
+    }
+
+    #[test]
+    fn test_line_of_sight() {
+        let world = Bounded::line_of_sight(DenseGrid::parse("a.b\n...\nc.d", |c| c), |c| *c != '.');
+        let sees = |x, y| {
+            let mut seen = world.neighbours[Vector::new([x, y])]
+                .iter()
+                .map(|point| world.grid()[*point])
+                .collect::<Vec<_>>();
+            seen.sort_unstable();
+            seen
+        };
+        assert_eq!(sees(0, 0), ['b', 'c', 'd']);
+        assert_eq!(sees(1, 1), ['a', 'b', 'c', 'd']);
+        assert_eq!(sees(1, 0), ['a', 'b']);
+    }
+}
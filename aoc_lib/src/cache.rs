@@ -0,0 +1,148 @@
+use std::{
+    env, fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+/// On-disk store of puzzle inputs and pages, laid out as `<root>/<year>/day_<NN>.txt`
+#[derive(Clone, Debug)]
+pub struct Cache {
+    root: PathBuf,
+}
+
+impl Cache {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Uses `AOC_CACHE_DIR` if set, otherwise `$XDG_CACHE_HOME/aoc` or `$HOME/.cache/aoc`
+    pub fn from_env() -> Option<Self> {
+        if let Ok(dir) = env::var("AOC_CACHE_DIR") {
+            return Some(Self::new(dir));
+        }
+        if let Ok(dir) = env::var("XDG_CACHE_HOME") {
+            return Some(Self::new(Path::new(&dir).join("aoc")));
+        }
+        env::var("HOME")
+            .ok()
+            .map(|home| Self::new(Path::new(&home).join(".cache").join("aoc")))
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn input_path(&self, year: usize, day: usize) -> PathBuf {
+        self.root
+            .join(year.to_string())
+            .join(format!("day_{day:02}.txt"))
+    }
+
+    pub fn read_input(&self, year: usize, day: usize) -> io::Result<Option<String>> {
+        read_optional(&self.input_path(year, day))
+    }
+
+    pub fn write_input(&self, year: usize, day: usize, input: &str) -> io::Result<()> {
+        write_creating_dirs(&self.input_path(year, day), input)
+    }
+
+    /// The puzzle description page, as served at `/<year>/day/<day>`
+    pub fn puzzle_path(&self, year: usize, day: usize) -> PathBuf {
+        self.root
+            .join(year.to_string())
+            .join(format!("day_{day:02}.html"))
+    }
+
+    pub fn read_puzzle(&self, year: usize, day: usize) -> io::Result<Option<String>> {
+        read_optional(&self.puzzle_path(year, day))
+    }
+
+    pub fn write_puzzle(&self, year: usize, day: usize, html: &str) -> io::Result<()> {
+        write_creating_dirs(&self.puzzle_path(year, day), html)
+    }
+
+    /// Log of answers already sent for a day, one `part<TAB>outcome<TAB>answer` line per submission
+    pub fn submissions_path(&self, year: usize, day: usize) -> PathBuf {
+        self.root
+            .join(year.to_string())
+            .join(format!("day_{day:02}_submissions.txt"))
+    }
+
+    pub fn read_submissions(
+        &self,
+        year: usize,
+        day: usize,
+    ) -> io::Result<Vec<(usize, String, String)>> {
+        let Some(log) = read_optional(&self.submissions_path(year, day))? else {
+            return Ok(vec![]);
+        };
+        Ok(log
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.splitn(3, '\t');
+                let part = fields.next()?.parse().ok()?;
+                let outcome = fields.next()?.to_string();
+                let answer = fields.next()?.to_string();
+                Some((part, outcome, answer))
+            })
+            .collect())
+    }
+
+    pub fn record_submission(
+        &self,
+        year: usize,
+        day: usize,
+        part: usize,
+        outcome: &str,
+        answer: &str,
+    ) -> io::Result<()> {
+        let path = self.submissions_path(year, day);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+        writeln!(file, "{part}\t{outcome}\t{answer}")
+    }
+}
+
+fn read_optional(path: &Path) -> io::Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+fn write_creating_dirs(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let root = env::temp_dir().join(format!("aoc_cache_test_{}", std::process::id()));
+        let cache = Cache::new(&root);
+
+        assert_eq!(cache.read_input(2017, 3).unwrap(), None);
+        cache.write_input(2017, 3, "265149").unwrap();
+        assert_eq!(
+            cache.input_path(2017, 3),
+            root.join("2017").join("day_03.txt")
+        );
+        assert_eq!(
+            cache.read_input(2017, 3).unwrap(),
+            Some("265149".to_string())
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
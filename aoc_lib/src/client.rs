@@ -0,0 +1,390 @@
+use std::{env, fmt};
+
+use reqwest::{
+    blocking::{Client, ClientBuilder},
+    header, StatusCode,
+};
+
+use crate::{
+    cache::Cache,
+    examples::{parse_examples, part_count, Example},
+    submit::{known_outcome, parse_response, Outcome},
+    AocError, ENVIRONMENT,
+};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Talks to adventofcode.com (or a stand-in), going through the on-disk [`Cache`] where possible.
+///
+/// [`AocClient::from_env`] reads `AOC_TOKEN`, `AOC_BASE_URL`, `AOC_CACHE_DIR` and `AOC_OFFLINE`;
+/// the `with_*` methods override them, e.g. to point tests at a [`crate::mock_server::MockServer`].
+#[derive(Clone, Debug)]
+pub struct AocClient {
+    base_url: String,
+    token: Option<String>,
+    cache: Option<Cache>,
+    offline: bool,
+}
+
+impl Default for AocClient {
+    fn default() -> Self {
+        Self {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            token: None,
+            cache: None,
+            offline: false,
+        }
+    }
+}
+
+impl AocClient {
+    pub fn from_env() -> Self {
+        let _ = ENVIRONMENT.as_ref().ok();
+        let client = Self {
+            token: env::var("AOC_TOKEN").ok(),
+            cache: Cache::from_env(),
+            offline: env::var("AOC_OFFLINE")
+                .is_ok_and(|v| !v.is_empty() && v != "0" && v != "false"),
+            ..Self::default()
+        };
+        match env::var("AOC_BASE_URL") {
+            Ok(base_url) => client.with_base_url(base_url),
+            Err(_) => client,
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    pub fn with_cache(mut self, cache: Option<Cache>) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    pub fn with_offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn cache(&self) -> Option<&Cache> {
+        self.cache.as_ref()
+    }
+
+    fn http(&self) -> Result<Client, AocError> {
+        let token = self.token.as_ref().ok_or(AocError::MissingToken)?;
+        let mut headers = header::HeaderMap::new();
+        headers.insert(
+            "Cookie",
+            header::HeaderValue::from_str(token).map_err(|_| AocError::InvalidToken)?,
+        );
+        Ok(ClientBuilder::new().default_headers(headers).build()?)
+    }
+
+    /// Returns the input for the given puzzle, served from the cache when possible.
+    ///
+    /// In offline mode a missing input fails with [`AocError::NotCached`] instead of a network
+    /// error.
+    pub fn input(&self, year: usize, day: usize) -> Result<String, AocError> {
+        if let Some(cache) = &self.cache {
+            let cached = cache
+                .read_input(year, day)
+                .map_err(|source| AocError::Cache {
+                    path: cache.input_path(year, day),
+                    source,
+                })?;
+            if let Some(input) = cached {
+                return Ok(input);
+            }
+        }
+
+        if self.offline {
+            return Err(AocError::NotCached { year, day });
+        }
+
+        let input = self.fetch_input(year, day)?;
+        if let Some(cache) = &self.cache {
+            cache
+                .write_input(year, day, &input)
+                .map_err(|source| AocError::Cache {
+                    path: cache.input_path(year, day),
+                    source,
+                })?;
+        }
+        Ok(input)
+    }
+
+    fn fetch_input(&self, year: usize, day: usize) -> Result<String, AocError> {
+        self.fetch(
+            &format!("{}/{year}/day/{day}/input", self.base_url),
+            year,
+            day,
+        )
+    }
+
+    fn fetch(&self, url: &str, year: usize, day: usize) -> Result<String, AocError> {
+        let x = self.http()?.get(url).send()?;
+        let status = x.status();
+        if status == StatusCode::BAD_REQUEST || status == StatusCode::NOT_FOUND {
+            return Err(AocError::NotUnlocked { year, day });
+        }
+        if !status.is_success() {
+            return Err(AocError::Status {
+                status: status.as_u16(),
+                body: x.text()?.trim().to_string(),
+            });
+        }
+        Ok(x.text()?.trim().to_string())
+    }
+
+    /// Returns the HTML of the puzzle page, served from the cache when possible.
+    ///
+    /// A cached page that only has part 1 is fetched again when online, as part 2 is added to
+    /// the page once part 1 has been solved.
+    pub fn puzzle(&self, year: usize, day: usize) -> Result<String, AocError> {
+        let mut cached = None;
+        if let Some(cache) = &self.cache {
+            cached = cache
+                .read_puzzle(year, day)
+                .map_err(|source| AocError::Cache {
+                    path: cache.puzzle_path(year, day),
+                    source,
+                })?;
+        }
+
+        match cached {
+            Some(html) if self.offline || part_count(&html) >= 2 => return Ok(html),
+            None if self.offline => return Err(AocError::NotCached { year, day }),
+            _ => {}
+        }
+
+        let html = self.fetch(&format!("{}/{year}/day/{day}", self.base_url), year, day)?;
+        if let Some(cache) = &self.cache {
+            cache
+                .write_puzzle(year, day, &html)
+                .map_err(|source| AocError::Cache {
+                    path: cache.puzzle_path(year, day),
+                    source,
+                })?;
+        }
+        Ok(html)
+    }
+
+    pub fn examples(&self, year: usize, day: usize) -> Result<Vec<Example>, AocError> {
+        Ok(parse_examples(&self.puzzle(year, day)?))
+    }
+
+    /// Submits an answer for the given part, unless the submission log already knows the result.
+    ///
+    /// Answers are never re-sent once they've been judged, and numeric answers outside a recorded
+    /// too-high/too-low bound are rejected locally.
+    pub fn submit(
+        &self,
+        year: usize,
+        day: usize,
+        part: usize,
+        answer: impl fmt::Display,
+    ) -> Result<Outcome, AocError> {
+        let answer = answer.to_string();
+
+        if let Some(cache) = &self.cache {
+            let submissions =
+                cache
+                    .read_submissions(year, day)
+                    .map_err(|source| AocError::Cache {
+                        path: cache.submissions_path(year, day),
+                        source,
+                    })?;
+            let previous = submissions
+                .iter()
+                .filter(|(p, _, _)| *p == part)
+                .filter_map(|(_, outcome, answer)| Some((outcome.parse().ok()?, answer.as_str())));
+            if let Some(outcome) = known_outcome(previous, &answer) {
+                return Ok(outcome);
+            }
+        }
+
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let response = self
+            .http()?
+            .post(url)
+            .form(&[("level", part.to_string()), ("answer", answer.clone())])
+            .send()?;
+        let status = response.status();
+        let body = response.text()?;
+        if !status.is_success() {
+            return Err(AocError::Status {
+                status: status.as_u16(),
+                body: body.trim().to_string(),
+            });
+        }
+        let outcome = parse_response(&body).ok_or(AocError::UnexpectedResponse(body))?;
+
+        // a rate-limited answer was never judged, so it may be sent again later
+        let judged = !matches!(outcome, Outcome::RateLimited { .. });
+        if let Some(cache) = self.cache.as_ref().filter(|_| judged) {
+            cache
+                .record_submission(year, day, part, &outcome.to_string(), &answer)
+                .map_err(|source| AocError::Cache {
+                    path: cache.submissions_path(year, day),
+                    source,
+                })?;
+        }
+
+        Ok(outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf, time::Duration};
+
+    use super::*;
+    use crate::mock_server::MockServer;
+
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let path = env::temp_dir().join(format!("aoc_client_{name}_{}", std::process::id()));
+            let _ = fs::remove_dir_all(&path);
+            Self(path)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn client(server: &MockServer, dir: &TempDir) -> AocClient {
+        AocClient::default()
+            .with_base_url(server.url())
+            .with_token("session=abc")
+            .with_cache(Some(Cache::new(&dir.0)))
+    }
+
+    #[test]
+    fn test_input_is_cached() {
+        let server = MockServer::start();
+        server.input(2017, 1, "1122\n");
+        let dir = TempDir::new("input_is_cached");
+        let client = client(&server, &dir);
+
+        assert_eq!(client.input(2017, 1).unwrap(), "1122");
+        assert_eq!(client.input(2017, 1).unwrap(), "1122");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].path, "/2017/day/1/input");
+        assert_eq!(requests[0].cookie.as_deref(), Some("session=abc"));
+
+        let offline = AocClient::default()
+            .with_cache(Some(Cache::new(&dir.0)))
+            .with_offline(true);
+        assert_eq!(offline.input(2017, 1).unwrap(), "1122");
+        assert!(matches!(
+            offline.input(2017, 2),
+            Err(AocError::NotCached { year: 2017, day: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_input_errors() {
+        let server = MockServer::start();
+        server.status("GET", "/2017/day/3/input", 500, "Internal Server Error");
+        let dir = TempDir::new("input_errors");
+        let client = client(&server, &dir);
+
+        assert!(matches!(
+            client.input(2017, 2),
+            Err(AocError::NotUnlocked { year: 2017, day: 2 })
+        ));
+        assert!(matches!(
+            client.input(2017, 3),
+            Err(AocError::Status { status: 500, .. })
+        ));
+        assert!(matches!(
+            AocClient::default()
+                .with_base_url(server.url())
+                .input(2017, 3),
+            Err(AocError::MissingToken)
+        ));
+    }
+
+    #[test]
+    fn test_examples() {
+        let server = MockServer::start();
+        let part_1 = r#"<article class="day-desc"><pre><code>+1, -2
+</code></pre><p>Result: <code><em>-1</em></code></p></article>"#;
+        server.puzzle(2018, 1, part_1);
+        let dir = TempDir::new("examples");
+        let client = client(&server, &dir);
+
+        let examples = client.examples(2018, 1).unwrap();
+        assert_eq!(examples.len(), 1);
+        assert_eq!(examples[0].input, "+1, -2");
+        assert_eq!(examples[0].expected_part_1.as_deref(), Some("-1"));
+        assert_eq!(examples[0].expected_part_2, None);
+
+        // only part 1 was cached, so the page is fetched again
+        server.puzzle(
+            2018,
+            1,
+            &format!(
+                r#"{part_1}<article class="day-desc"><p>Now <code><em>2</em></code></p></article>"#
+            ),
+        );
+        let examples = client.examples(2018, 1).unwrap();
+        assert_eq!(examples[0].expected_part_2.as_deref(), Some("2"));
+        client.examples(2018, 1).unwrap();
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[test]
+    fn test_submit() {
+        let server = MockServer::start();
+        server.answer(
+            2017,
+            1,
+            "<article><p>That's not the right answer; your answer is too high.</p></article>",
+        );
+        let dir = TempDir::new("submit");
+        let client = client(&server, &dir);
+
+        assert_eq!(client.submit(2017, 1, 1, 1500).unwrap(), Outcome::TooHigh);
+        assert_eq!(client.submit(2017, 1, 1, 1500).unwrap(), Outcome::TooHigh);
+        assert_eq!(client.submit(2017, 1, 1, 2000).unwrap(), Outcome::TooHigh);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].body, "level=1&answer=1500");
+
+        server.rate_limited(2017, 1, Duration::from_secs(45));
+        assert_eq!(
+            client.submit(2017, 1, 1, 1000).unwrap(),
+            Outcome::RateLimited {
+                wait: Duration::from_secs(45)
+            }
+        );
+        server.answer(
+            2017,
+            1,
+            "<article><p>That's the right answer!</p></article>",
+        );
+        assert_eq!(client.submit(2017, 1, 1, 1000).unwrap(), Outcome::Correct);
+        assert_eq!(server.requests().len(), 3);
+    }
+}
@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Where a sequence of states starts repeating itself
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Cycle {
+    /// Steps taken before the first state that comes back
+    pub start: usize,
+    /// Steps it takes to come back, 1 for a state that steps to itself
+    pub length: usize,
+}
+
+impl Cycle {
+    pub fn is_steady(&self) -> bool {
+        self.length == 1
+    }
+
+    /// The earliest step with the same state as step `n`
+    pub fn index(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+/// Finds the cycle with Brent's algorithm, which only ever keeps two states around but steps
+/// through the sequence about three times. Never returns if the states don't repeat.
+pub fn brent<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    Cycle { start, length }
+}
+
+/// Finds the cycle by remembering every state, which steps through the sequence only once.
+/// Never returns if the states don't repeat.
+pub fn hashed<S: Clone + Eq + Hash>(initial: S, step: impl FnMut(&S) -> S) -> Cycle {
+    run(initial, step, S::clone, usize::MAX)
+        .1
+        .expect("Only stops at a cycle")
+}
+
+/// The state after `n` steps, skipping ahead once the states repeat
+pub fn nth_state<S: Clone + Eq + Hash>(initial: S, step: impl FnMut(&S) -> S, n: usize) -> S {
+    let (mut history, cycle) = run(initial, step, S::clone, n);
+    match cycle {
+        Some(cycle) => history.swap_remove(cycle.index(n)),
+        None => history.pop().expect("There is always the initial state"),
+    }
+}
+
+/// Like [`nth_state`], for states that come back moved along, such as a pattern that settles
+/// down and then walks off in one direction.
+///
+/// `shape` splits a state into what it looks like, wherever it is, and its position along the
+/// direction it moves in. `shift` moves a state along by the given amount.
+pub fn nth_state_drifting<S, K: Eq + Hash>(
+    initial: S,
+    step: impl FnMut(&S) -> S,
+    n: usize,
+    shape: impl Fn(&S) -> (K, i64),
+    shift: impl Fn(&S, i64) -> S,
+) -> S {
+    let (mut history, cycle) = run(initial, step, |state| shape(state).0, n);
+    let Some(cycle) = cycle else {
+        return history.pop().expect("There is always the initial state");
+    };
+
+    let position = |steps: usize| shape(&history[steps]).1;
+    let drift = position(cycle.start + cycle.length) - position(cycle.start);
+    let cycles = ((n - cycle.start) / cycle.length) as i64;
+    shift(&history[cycle.index(n)], drift * cycles)
+}
+
+/// Every state until one with a key seen before or until `limit` steps, whichever comes first
+fn run<S, K: Eq + Hash>(
+    initial: S,
+    mut step: impl FnMut(&S) -> S,
+    key: impl Fn(&S) -> K,
+    limit: usize,
+) -> (Vec<S>, Option<Cycle>) {
+    let mut history = vec![initial];
+    let mut seen = HashMap::new();
+    loop {
+        let steps = history.len() - 1;
+        let current = &history[steps];
+        if let Some(start) = seen.insert(key(current), steps) {
+            let cycle = Cycle {
+                start,
+                length: steps - start,
+            };
+            return (history, Some(cycle));
+        }
+        if steps == limit {
+            return (history, None);
+        }
+        let next = step(current);
+        history.push(next);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, ..., 10, then back to 5
+    fn step(x: &usize) -> usize {
+        if *x < 10 {
+            x + 1
+        } else {
+            5
+        }
+    }
+
+    #[test]
+    fn test_find() {
+        let expected = Cycle {
+            start: 5,
+            length: 6,
+        };
+        assert_eq!(brent(0, step), expected);
+        assert_eq!(hashed(0, step), expected);
+        assert_eq!(
+            hashed(10, step),
+            Cycle {
+                start: 0,
+                length: 6
+            }
+        );
+        assert!(brent(3, |_| 3).is_steady());
+    }
+
+    #[test]
+    fn test_nth_state() {
+        assert_eq!(nth_state(0, step, 3), 3);
+        assert_eq!(nth_state(0, step, 11), 5);
+        assert_eq!(nth_state(0, step, 1_000_000), 10);
+    }
+
+    #[test]
+    fn test_nth_state_drifting() {
+        // settles into two cells walking right after a couple of steps
+        let walk = |cells: &Vec<i64>| match cells.len() {
+            4 => vec![cells[0], cells[3]],
+            _ => cells.iter().map(|x| x + 1).collect(),
+        };
+        let shape = |cells: &Vec<i64>| {
+            let min = cells[0];
+            (cells.iter().map(|x| x - min).collect::<Vec<_>>(), min)
+        };
+        let shift = |cells: &Vec<i64>, by| cells.iter().map(|x| x + by).collect();
+
+        let initial = vec![0, 1, 2, 4];
+        assert_eq!(
+            nth_state_drifting(initial.clone(), walk, 0, shape, shift),
+            initial
+        );
+        assert_eq!(
+            nth_state_drifting(initial.clone(), walk, 3, shape, shift),
+            [2, 6]
+        );
+        assert_eq!(
+            nth_state_drifting(initial, walk, 1_000_000_000_000, shape, shift),
+            [999_999_999_999, 1_000_000_000_003]
+        );
+    }
+}
@@ -0,0 +1,286 @@
+//! Headings on a 2D grid.
+//!
+//! The unit vectors follow the way [`Grid::parse`](crate::grid::Grid::parse) lays out the input:
+//! x grows to the right and y grows downwards, so [`Direction4::Up`] is `(0, -1)` and turning
+//! right is clockwise on screen.
+
+use crate::vector::Vector;
+use std::str::FromStr;
+
+/// One of the four directions along the axes
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction4 {
+    /// Clockwise from up
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    pub fn turn_right(self) -> Self {
+        self.turn(1)
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.turn(-1)
+    }
+
+    pub fn reverse(self) -> Self {
+        self.turn(2)
+    }
+
+    /// Turns right by the given number of quarter turns, or left if it's negative
+    pub fn turn(self, quarter_turns: i32) -> Self {
+        Self::ALL[(self as i32 + quarter_turns).rem_euclid(4) as usize]
+    }
+
+    pub fn vector(self) -> Vector<2> {
+        match self {
+            Self::Up => Vector::new([0, -1]),
+            Self::Right => Vector::new([1, 0]),
+            Self::Down => Vector::new([0, 1]),
+            Self::Left => Vector::new([-1, 0]),
+        }
+    }
+}
+
+/// Accepts `U/R/D/L`, compass points `N/E/S/W`, arrows `^>v<`, and the digits `0-3` for
+/// right, down, left and up, as hex-encoded instructions use them
+impl TryFrom<char> for Direction4 {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, char> {
+        match c {
+            'U' | 'N' | '^' | '3' => Ok(Self::Up),
+            'R' | 'E' | '>' | '0' => Ok(Self::Right),
+            'D' | 'S' | 'v' | '1' => Ok(Self::Down),
+            'L' | 'W' | '<' | '2' => Ok(Self::Left),
+            c => Err(c),
+        }
+    }
+}
+
+/// A single character, see [`Direction4::try_from`]
+impl FromStr for Direction4 {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Self::try_from(c).map_err(|c| format!("Invalid direction: {c}")),
+            _ => Err(format!("Invalid direction: {s:?}")),
+        }
+    }
+}
+
+/// One of the eight directions to a touching cell, diagonals included
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// Clockwise from up
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    /// An eighth of a turn clockwise
+    pub fn turn_right(self) -> Self {
+        self.turn(1)
+    }
+
+    /// An eighth of a turn anticlockwise
+    pub fn turn_left(self) -> Self {
+        self.turn(-1)
+    }
+
+    pub fn reverse(self) -> Self {
+        self.turn(4)
+    }
+
+    /// Turns right by the given number of eighth turns, or left if it's negative
+    pub fn turn(self, eighth_turns: i32) -> Self {
+        Self::ALL[(self as i32 + eighth_turns).rem_euclid(8) as usize]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    pub fn vector(self) -> Vector<2> {
+        match self {
+            Self::Up => Vector::new([0, -1]),
+            Self::UpRight => Vector::new([1, -1]),
+            Self::Right => Vector::new([1, 0]),
+            Self::DownRight => Vector::new([1, 1]),
+            Self::Down => Vector::new([0, 1]),
+            Self::DownLeft => Vector::new([-1, 1]),
+            Self::Left => Vector::new([-1, 0]),
+            Self::UpLeft => Vector::new([-1, -1]),
+        }
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        Self::ALL[direction as usize * 2]
+    }
+}
+
+/// Compass points like `N` or `sw` in either case, or anything [`Direction4`] parses
+impl FromStr for Direction8 {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        match s.to_ascii_uppercase().as_str() {
+            "NE" => Ok(Self::UpRight),
+            "SE" => Ok(Self::DownRight),
+            "SW" => Ok(Self::DownLeft),
+            "NW" => Ok(Self::UpLeft),
+            // the arrow for down is lower case, so try as given first
+            upper => s
+                .parse::<Direction4>()
+                .or_else(|_| upper.parse())
+                .map(Self::from),
+        }
+    }
+}
+
+/// Something walking about a grid, like a robot following instructions
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Turtle {
+    pub position: Vector<2>,
+    pub heading: Direction4,
+}
+
+impl Turtle {
+    pub fn new(position: Vector<2>, heading: Direction4) -> Self {
+        Self { position, heading }
+    }
+
+    /// The point one step ahead
+    pub fn ahead(&self) -> Vector<2> {
+        self.position + self.heading.vector()
+    }
+
+    pub fn forward(&mut self, steps: i32) {
+        self.position += self.heading.vector() * steps;
+    }
+
+    /// Moves without changing heading
+    pub fn shift(&mut self, direction: Direction4, steps: i32) {
+        self.position += direction.vector() * steps;
+    }
+
+    pub fn turn_right(&mut self) {
+        self.heading = self.heading.turn_right();
+    }
+
+    pub fn turn_left(&mut self) {
+        self.heading = self.heading.turn_left();
+    }
+
+    /// See [`Direction4::turn`]
+    pub fn turn(&mut self, quarter_turns: i32) {
+        self.heading = self.heading.turn(quarter_turns);
+    }
+
+    /// Every point passed on the way `steps` forward, ending where it stops
+    pub fn walk(&mut self, steps: i32) -> impl Iterator<Item = Vector<2>> {
+        let (start, step) = (self.position, self.heading.vector());
+        self.forward(steps);
+        (1..=steps).map(move |n| start + step * n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_direction4() {
+        use Direction4::*;
+        assert_eq!(Up.turn_right(), Right);
+        assert_eq!(Up.turn_left(), Left);
+        assert_eq!(Left.reverse(), Right);
+        assert_eq!(Down.turn(-3), Left);
+        assert_eq!(Right.turn(9), Down);
+        for direction in Direction4::ALL {
+            assert_eq!(direction.reverse().vector(), -direction.vector());
+            assert_eq!(
+                direction.turn_right().vector(),
+                direction.vector().rotate_right()
+            );
+            for quarter_turns in -5..=5 {
+                assert_eq!(
+                    direction.turn(quarter_turns).vector(),
+                    direction.vector().rotate(quarter_turns)
+                );
+            }
+        }
+
+        let parsed = "UNR>0v1W"
+            .chars()
+            .map(|c| Direction4::try_from(c).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(parsed, [Up, Up, Right, Right, Right, Down, Down, Left]);
+        assert_eq!("^".parse(), Ok(Up));
+        assert!("x".parse::<Direction4>().is_err());
+        assert!("UL".parse::<Direction4>().is_err());
+    }
+
+    #[test]
+    fn test_direction8() {
+        use Direction8::*;
+        assert_eq!(Up.turn_right(), UpRight);
+        assert_eq!(Up.turn_left(), UpLeft);
+        assert_eq!(DownLeft.reverse(), UpRight);
+        assert!(DownLeft.is_diagonal());
+        assert_eq!(Direction8::from(Direction4::Left), Left);
+        let mut vectors = Direction8::ALL.map(Direction8::vector).to_vec();
+        vectors.sort_unstable_by_key(|vector| **vector);
+        let mut neighbours = Vector::ZERO.neighbours_diagonals().collect::<Vec<_>>();
+        neighbours.sort_unstable_by_key(|vector| **vector);
+        assert_eq!(vectors, neighbours);
+
+        assert_eq!("sw".parse(), Ok(DownLeft));
+        assert_eq!("NE".parse(), Ok(UpRight));
+        assert_eq!("v".parse(), Ok(Down));
+        assert_eq!("w".parse(), Ok(Left));
+        assert!("NS".parse::<Direction8>().is_err());
+    }
+
+    #[test]
+    fn test_turtle() {
+        let mut turtle = Turtle::new(Vector::ZERO, Direction4::Up);
+        turtle.forward(3);
+        turtle.turn_right();
+        assert_eq!(turtle.ahead(), Vector::new([1, -3]));
+        assert_eq!(
+            turtle.walk(2).collect::<Vec<_>>(),
+            [[1, -3], [2, -3]].map(Vector::new)
+        );
+        turtle.turn(-2);
+        turtle.shift(Direction4::Down, 5);
+        assert_eq!(turtle.position, Vector::new([2, 2]));
+        assert_eq!(turtle.heading, Direction4::Left);
+    }
+}
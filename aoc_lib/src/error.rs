@@ -0,0 +1,72 @@
+use std::{fmt, io, path::PathBuf};
+
+#[derive(Debug)]
+pub enum AocError {
+    /// `AOC_TOKEN` was not set in the environment or `.env`
+    MissingToken,
+    /// `AOC_TOKEN` could not be used as a header value
+    InvalidToken,
+    /// The puzzle exists but has not been unlocked yet (the site answers 400 or 404)
+    NotUnlocked {
+        year: usize,
+        day: usize,
+    },
+    /// Any other non-2xx response
+    Status {
+        status: u16,
+        body: String,
+    },
+    Network(reqwest::Error),
+    /// The site answered with a page we don't know how to interpret
+    UnexpectedResponse(String),
+    /// Offline mode is on and the input has not been cached
+    NotCached {
+        year: usize,
+        day: usize,
+    },
+    Cache {
+        path: PathBuf,
+        source: io::Error,
+    },
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::MissingToken => write!(
+                f,
+                "AOC_TOKEN must be specified as environment variable or in .env"
+            ),
+            AocError::InvalidToken => write!(f, "AOC_TOKEN is not a valid header value"),
+            AocError::NotUnlocked { year, day } => {
+                write!(f, "Puzzle for {year} day {day} is not unlocked yet")
+            }
+            AocError::Status { status, body } => write!(f, "Request error: {status}: {body}"),
+            AocError::Network(e) => write!(f, "Network error: {e}"),
+            AocError::UnexpectedResponse(body) => write!(f, "Unexpected response: {body}"),
+            AocError::NotCached { year, day } => write!(
+                f,
+                "Input for {year} day {day} is not cached and AOC_OFFLINE is set"
+            ),
+            AocError::Cache { path, source } => {
+                write!(f, "Cache error at {}: {source}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocError::Network(e) => Some(e),
+            AocError::Cache { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for AocError {
+    fn from(e: reqwest::Error) -> Self {
+        AocError::Network(e)
+    }
+}
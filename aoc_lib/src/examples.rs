@@ -0,0 +1,187 @@
+/// An example from the puzzle text, with the answers the text gives for it.
+///
+/// Only `<pre><code>` blocks are picked up; examples given inline in a sentence are not.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Example {
+    pub input: String,
+    pub expected_part_1: Option<String>,
+    pub expected_part_2: Option<String>,
+}
+
+/// Pulls examples out of a puzzle page.
+///
+/// Each part is an `<article class="day-desc">`. An example's answer is taken to be the last
+/// emphasised code (`<code><em>..</em></code>`) between its block and the next one. Part 2
+/// usually reuses part 1's examples, so when its article has no blocks of its own the answer
+/// is attached to the first example.
+pub fn parse_examples(html: &str) -> Vec<Example> {
+    let mut examples: Vec<Example> = vec![];
+
+    for (part, article) in articles(html).into_iter().take(2).enumerate() {
+        let blocks = blocks(article);
+
+        if blocks.is_empty() {
+            if let (Some(example), Some(answer)) = (examples.first_mut(), last_answer(article)) {
+                set_expected(example, part, answer);
+            }
+            continue;
+        }
+
+        for (i, (input, _, end)) in blocks.iter().enumerate() {
+            let next_start = blocks.get(i + 1).map_or(article.len(), |next| next.1);
+            let answer = last_answer(&article[*end..next_start]);
+
+            let index = match examples.iter().position(|e| e.input == *input) {
+                Some(index) => index,
+                None => {
+                    examples.push(Example {
+                        input: input.clone(),
+                        ..Default::default()
+                    });
+                    examples.len() - 1
+                }
+            };
+            if let Some(answer) = answer {
+                set_expected(&mut examples[index], part, answer);
+            }
+        }
+    }
+
+    examples
+}
+
+fn set_expected(example: &mut Example, part: usize, answer: String) {
+    match part {
+        0 => example.expected_part_1 = Some(answer),
+        _ => example.expected_part_2 = Some(answer),
+    }
+}
+
+const ARTICLE: &str = "<article class=\"day-desc\"";
+
+/// Number of parts whose description is on the page; part 2 only appears once part 1 is solved
+pub fn part_count(html: &str) -> usize {
+    html.matches(ARTICLE).count()
+}
+
+/// The puzzle's name, from the `--- Day N: Title ---` heading of part 1
+pub fn parse_title(html: &str) -> Option<String> {
+    let heading = articles(html).into_iter().next()?;
+    let start = heading.find("<h2>")? + "<h2>".len();
+    let end = start + heading[start..].find("</h2>")?;
+    let heading = decode(&heading[start..end]);
+    let (_, title) = heading
+        .trim_matches(|c| c == '-' || c == ' ')
+        .split_once(": ")?;
+    Some(title.to_string())
+}
+
+fn articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+    while let Some(start) = rest.find(ARTICLE) {
+        let body = &rest[start..];
+        let Some(end) = body.find("</article>") else {
+            break;
+        };
+        articles.push(&body[..end]);
+        rest = &body[end..];
+    }
+    articles
+}
+
+// (decoded block contents, offset of the block, offset just past the block)
+fn blocks(article: &str) -> Vec<(String, usize, usize)> {
+    let mut blocks = vec![];
+    let mut offset = 0;
+    while let Some(start) = article[offset..].find("<pre><code>") {
+        let start = offset + start;
+        let contents = start + "<pre><code>".len();
+        let Some(len) = article[contents..].find("</code></pre>") else {
+            break;
+        };
+        offset = contents + len + "</code></pre>".len();
+        blocks.push((decode(&article[contents..contents + len]), start, offset));
+    }
+    blocks
+}
+
+fn last_answer(text: &str) -> Option<String> {
+    ["<code><em>", "<em><code>"]
+        .into_iter()
+        .filter_map(|open| {
+            let start = text.rfind(open)? + open.len();
+            let end = start + text[start..].find("</")?;
+            Some((start, decode(&text[start..end])))
+        })
+        .max_by_key(|(start, _)| *start)
+        .map(|(_, answer)| answer)
+}
+
+/// Strips tags and decodes the handful of entities the puzzle pages use
+fn decode(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+        .trim_end_matches('\n')
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2>
+<p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+</code></pre>
+<p>Adding these together produces <code><em>50</em></code>.</p>
+<pre><code>a&lt;b
+</code></pre>
+<p>Here there are <em>two</em> things, giving <code><em>7</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>54159</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>In the first example, the answer is now <code><em>281</em></code>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_parse_examples() {
+        assert_eq!(
+            parse_examples(PAGE),
+            vec![
+                Example {
+                    input: "1abc2\npqr3stu8vwx".to_string(),
+                    expected_part_1: Some("50".to_string()),
+                    expected_part_2: Some("281".to_string()),
+                },
+                Example {
+                    input: "a<b".to_string(),
+                    expected_part_1: Some("7".to_string()),
+                    expected_part_2: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_title() {
+        assert_eq!(parse_title(PAGE), Some("Trebuchet?!".to_string()));
+        assert_eq!(parse_title("<html></html>"), None);
+    }
+}
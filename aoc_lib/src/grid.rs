@@ -1,5 +1,30 @@
-use crate::vector::Vector;
-use std::collections::HashMap;
+use crate::vector::{Neighbourhood, Vector};
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// How a cell is drawn when a grid is displayed
+pub trait ToChar {
+    fn to_char(&self) -> char;
+}
+
+impl ToChar for char {
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+/// `#` for true and `.` for false, the way the puzzles draw them
+impl ToChar for bool {
+    fn to_char(&self) -> char {
+        if *self {
+            '#'
+        } else {
+            '.'
+        }
+    }
+}
 
 #[derive(Clone)]
 pub struct Grid<T> {
@@ -59,4 +84,923 @@ impl<T> Grid<T> {
             .map(|(point, value)| (*point, f(*point, value)))
             .collect()
     }
+
+    /// The smallest and largest coordinates in use, or `None` if the grid is empty
+    pub fn bounds(&self) -> Option<(Vector<2>, Vector<2>)> {
+        let mut points = self.keys();
+        let first = points.next()?;
+        Some(points.fold((first, first), |(min, max), point| {
+            (
+                Vector::new([min[0].min(point[0]), min[1].min(point[1])]),
+                Vector::new([max[0].max(point[0]), max[1].max(point[1])]),
+            )
+        }))
+    }
+
+    /// Draws every row within the bounds, top to bottom, using `fill` where there's no point
+    pub fn render(&self, fill: char, f: impl Fn(&T) -> char) -> String {
+        let Some((min, max)) = self.bounds() else {
+            return String::new();
+        };
+        (min[1]..=max[1])
+            .map(|y| {
+                (min[0]..=max[0])
+                    .map(|x| self.get(Vector::new([x, y])).map_or(fill, &f))
+                    .collect::<String>()
+            })
+            .join("\n")
+    }
+}
+
+/// Transforms, each returning a new grid. They work on the rectangle the bounds make, which
+/// keeps its top left corner, and points the grid doesn't have stay missing.
+impl<T: Clone> Grid<T> {
+    /// The rectangle the bounds make as a dense grid, along with its top left corner
+    fn to_dense(&self) -> (Vector<2>, DenseGrid<Option<T>>) {
+        let (min, max) = self.bounds().unwrap_or_default();
+        let size = max - min + 1;
+        let dense = DenseGrid::from_fn(size[0] as usize, size[1] as usize, |point| {
+            self.get(min + point).cloned()
+        });
+        (min, dense)
+    }
+
+    fn from_dense(top_left: Vector<2>, dense: DenseGrid<Option<T>>) -> Self {
+        let inner = dense
+            .iter()
+            .filter_map(|(point, value)| Some((top_left + point, value.clone()?)))
+            .collect();
+        Self::new(inner)
+    }
+
+    fn transformed(&self, f: impl Fn(&DenseGrid<Option<T>>) -> DenseGrid<Option<T>>) -> Self {
+        let (top_left, dense) = self.to_dense();
+        Self::from_dense(top_left, f(&dense))
+    }
+
+    /// See [`DenseGrid::transpose`]
+    pub fn transpose(&self) -> Self {
+        self.transformed(DenseGrid::transpose)
+    }
+
+    /// Rotates a quarter turn clockwise
+    pub fn rotate_90(&self) -> Self {
+        self.transformed(DenseGrid::rotate_90)
+    }
+
+    pub fn rotate_180(&self) -> Self {
+        self.transformed(DenseGrid::rotate_180)
+    }
+
+    /// Rotates a quarter turn anticlockwise
+    pub fn rotate_270(&self) -> Self {
+        self.transformed(DenseGrid::rotate_270)
+    }
+
+    /// Mirrors left to right
+    pub fn flip_horizontal(&self) -> Self {
+        self.transformed(DenseGrid::flip_horizontal)
+    }
+
+    /// Mirrors top to bottom
+    pub fn flip_vertical(&self) -> Self {
+        self.transformed(DenseGrid::flip_vertical)
+    }
+
+    /// See [`DenseGrid::symmetries`]
+    pub fn symmetries(&self) -> impl Iterator<Item = Self> {
+        let (top_left, dense) = self.to_dense();
+        dense
+            .symmetries()
+            .map(move |dense| Self::from_dense(top_left, dense))
+    }
+
+    /// The points inside the rectangle, moved so that `top_left` is at `(0, 0)`
+    pub fn crop(&self, top_left: Vector<2>, width: usize, height: usize) -> Self {
+        let size = Vector::new([width as i32, height as i32]);
+        let inner = self
+            .iter()
+            .map(|(point, value)| (point - top_left, value))
+            .filter(|(point, _)| (0..2).all(|axis| (0..size[axis]).contains(&point[axis])))
+            .map(|(point, value)| (point, value.clone()))
+            .collect();
+        Self::new(inner)
+    }
+
+    /// Cuts the rectangle the bounds make into `size` by `size` tiles, row by row, each with its
+    /// points moved to start from `(0, 0)`. The tiles along the right and bottom can stick out
+    /// past the bounds.
+    pub fn tiles(&self, size: usize) -> Vec<Self> {
+        assert!(size > 0, "Tiles can't be empty");
+        let Some((min, max)) = self.bounds() else {
+            return vec![];
+        };
+        let side = size as i32;
+        let across = ((max[0] - min[0]) / side + 1) as usize;
+        let down = ((max[1] - min[1]) / side + 1) as usize;
+        let mut tiles = vec![HashMap::new(); across * down];
+        for (point, value) in self.iter() {
+            let offset = point - min;
+            let tile = offset / side;
+            tiles[tile[1] as usize * across + tile[0] as usize]
+                .insert(offset % side, value.clone());
+        }
+        tiles.into_iter().map(Self::new).collect()
+    }
+
+    /// Puts `size` by `size` tiles back together, the reverse of [`Grid::tiles`]. Unlike a
+    /// [`DenseGrid`], a sparse tile doesn't know how big it is, so it has to be told.
+    ///
+    /// `None` if a tile has a point outside its size, or the last row isn't full.
+    pub fn stitch(tiles: &[Self], tiles_per_row: usize, size: usize) -> Option<Self> {
+        if tiles_per_row == 0 || !tiles.len().is_multiple_of(tiles_per_row) {
+            return None;
+        }
+        let side = i32::try_from(size).ok()?;
+        let mut inner = HashMap::new();
+        for (index, tile) in tiles.iter().enumerate() {
+            let top_left = Vector::new([
+                (index % tiles_per_row) as i32,
+                (index / tiles_per_row) as i32,
+            ]) * side;
+            for (point, value) in tile.iter() {
+                if !(0..2).all(|axis| (0..side).contains(&point[axis])) {
+                    return None;
+                }
+                inner.insert(top_left + point, value.clone());
+            }
+        }
+        Some(Self::new(inner))
+    }
+}
+
+impl<T: ToChar> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render(' ', T::to_char))
+    }
+}
+
+/// Searches through a grid, over the points it has; points it doesn't have are never passable
+pub trait Search {
+    type Cell;
+
+    /// The value at `point`, if the grid has it
+    fn cell(&self, point: Vector<2>) -> Option<&Self::Cell>;
+
+    /// Every point the grid has
+    fn points(&self) -> impl Iterator<Item = Vector<2>>;
+
+    /// How many steps it takes to reach each point that can be reached from `start`
+    fn bfs_distances(
+        &self,
+        start: Vector<2>,
+        neighbourhood: Neighbourhood,
+        passable: impl Fn(&Self::Cell) -> bool,
+    ) -> HashMap<Vector<2>, usize> {
+        distances(bfs(start, None, neighbourhood, |point| {
+            self.cell(point).is_some_and(&passable)
+        }))
+    }
+
+    /// One of the shortest paths from `start` to `goal`, both included
+    fn shortest_path(
+        &self,
+        start: Vector<2>,
+        goal: Vector<2>,
+        neighbourhood: Neighbourhood,
+        passable: impl Fn(&Self::Cell) -> bool,
+    ) -> Option<Vec<Vector<2>>> {
+        let reached = bfs(start, Some(goal), neighbourhood, |point| {
+            self.cell(point).is_some_and(&passable)
+        });
+        path(&reached, start, goal)
+    }
+
+    /// The region of matching points around `start`, which is empty if `start` doesn't match
+    fn flood_fill(
+        &self,
+        start: Vector<2>,
+        neighbourhood: Neighbourhood,
+        predicate: impl Fn(&Self::Cell) -> bool,
+    ) -> HashSet<Vector<2>> {
+        let matches = |point| self.cell(point).is_some_and(&predicate);
+        if !matches(start) {
+            return HashSet::new();
+        }
+        bfs(start, None, neighbourhood, matches)
+            .into_keys()
+            .collect()
+    }
+
+    /// Every separate region of matching points
+    fn connected_components(
+        &self,
+        neighbourhood: Neighbourhood,
+        predicate: impl Fn(&Self::Cell) -> bool,
+    ) -> Vec<HashSet<Vector<2>>> {
+        let matches = |point| self.cell(point).is_some_and(&predicate);
+        let mut seen = HashSet::new();
+        let mut components = vec![];
+        for point in self.points() {
+            if seen.contains(&point) || !matches(point) {
+                continue;
+            }
+            let component = bfs(point, None, neighbourhood, matches)
+                .into_keys()
+                .collect::<HashSet<_>>();
+            seen.extend(component.iter().copied());
+            components.push(component);
+        }
+        components
+    }
+}
+
+impl<T> Search for Grid<T> {
+    type Cell = T;
+
+    fn cell(&self, point: Vector<2>) -> Option<&T> {
+        self.get(point)
+    }
+
+    fn points(&self) -> impl Iterator<Item = Vector<2>> {
+        self.keys()
+    }
+}
+
+/// A rectangular grid stored row by row, for puzzles whose map has a fixed size.
+///
+/// Points are `(x, y)` with `(0, 0)` in the top left, as in [`Grid::parse`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct DenseGrid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> DenseGrid<T> {
+    /// Takes the cells in row-major order
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "A {width}x{height} grid needs {} cells",
+            width * height
+        );
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    /// Builds each cell from its point, row by row
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Vector<2>) -> T) -> Self {
+        Self::from_xy(width, height, |x, y| f(Vector::new([x as i32, y as i32])))
+    }
+
+    fn from_xy(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+        Self::new(width, height, cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Vector<2>) -> bool {
+        self.index(point).is_some()
+    }
+
+    fn index(&self, point: Vector<2>) -> Option<usize> {
+        let x = usize::try_from(point[0]).ok().filter(|x| *x < self.width)?;
+        let y = usize::try_from(point[1])
+            .ok()
+            .filter(|y| *y < self.height)?;
+        Some(y * self.width + x)
+    }
+
+    fn point(&self, index: usize) -> Vector<2> {
+        Vector::new([(index % self.width) as i32, (index / self.width) as i32])
+    }
+
+    pub fn get(&self, point: Vector<2>) -> Option<&T> {
+        self.cells.get(self.index(point)?)
+    }
+
+    pub fn get_mut(&mut self, point: Vector<2>) -> Option<&mut T> {
+        let index = self.index(point)?;
+        self.cells.get_mut(index)
+    }
+
+    /// Panics if the point is outside the grid
+    pub fn insert(&mut self, point: Vector<2>, value: T) {
+        self[point] = value;
+    }
+
+    /// Every point with its value, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Vector<2>, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, value)| (self.point(index), value))
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = Vector<2>> + use<'_, T> {
+        (0..self.cells.len()).map(|index| self.point(index))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // a grid without columns has no cells, so there are no rows to split either way
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The up to 4 orthogonal neighbours of a point that are inside the grid
+    pub fn neighbours(&self, point: Vector<2>) -> impl Iterator<Item = Vector<2>> + use<'_, T> {
+        point
+            .neighbours()
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// The up to 8 neighbours of a point, diagonals included, that are inside the grid
+    pub fn neighbours_diagonals(
+        &self,
+        point: Vector<2>,
+    ) -> impl Iterator<Item = Vector<2>> + use<'_, T> {
+        point
+            .neighbours_diagonals()
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// Panics unless every line is as long as the first
+    pub fn parse<F>(input: &str, f: F) -> Self
+    where
+        F: Fn(char) -> T,
+    {
+        let width = input.lines().next().map_or(0, |line| line.chars().count());
+        let mut height = 0;
+        let mut cells = vec![];
+        for line in input.lines() {
+            let len = cells.len();
+            cells.extend(line.chars().map(&f));
+            assert_eq!(
+                cells.len() - len,
+                width,
+                "Line {height} is not {width} wide"
+            );
+            height += 1;
+        }
+        Self::new(width, height, cells)
+    }
+
+    pub fn tick<F>(&mut self, mut f: F)
+    where
+        F: FnMut(Vector<2>, &T) -> T,
+    {
+        self.cells = self.iter().map(|(point, value)| f(point, value)).collect()
+    }
+
+    /// Draws every row, top to bottom
+    pub fn render(&self, f: impl Fn(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&f).collect::<String>())
+            .join("\n")
+    }
+}
+
+impl<T: ToChar> fmt::Display for DenseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render(T::to_char))
+    }
+}
+
+/// Transforms, each returning a new grid
+impl<T: Clone> DenseGrid<T> {
+    fn at(&self, x: usize, y: usize) -> T {
+        self.cells[y * self.width + x].clone()
+    }
+
+    /// Swaps rows and columns, mirroring along the diagonal from the top left
+    pub fn transpose(&self) -> Self {
+        Self::from_xy(self.height, self.width, |x, y| self.at(y, x))
+    }
+
+    /// Rotates a quarter turn clockwise
+    pub fn rotate_90(&self) -> Self {
+        Self::from_xy(self.height, self.width, |x, y| {
+            self.at(y, self.height - 1 - x)
+        })
+    }
+
+    pub fn rotate_180(&self) -> Self {
+        Self::from_xy(self.width, self.height, |x, y| {
+            self.at(self.width - 1 - x, self.height - 1 - y)
+        })
+    }
+
+    /// Rotates a quarter turn anticlockwise
+    pub fn rotate_270(&self) -> Self {
+        Self::from_xy(self.height, self.width, |x, y| {
+            self.at(self.width - 1 - y, x)
+        })
+    }
+
+    /// Mirrors left to right
+    pub fn flip_horizontal(&self) -> Self {
+        Self::from_xy(self.width, self.height, |x, y| {
+            self.at(self.width - 1 - x, y)
+        })
+    }
+
+    /// Mirrors top to bottom
+    pub fn flip_vertical(&self) -> Self {
+        Self::from_xy(self.width, self.height, |x, y| {
+            self.at(x, self.height - 1 - y)
+        })
+    }
+
+    /// The 8 ways the grid can be rotated and flipped, starting with the grid itself
+    pub fn symmetries(&self) -> impl Iterator<Item = Self> {
+        let flipped = self.flip_horizontal();
+        [
+            self.clone(),
+            self.rotate_90(),
+            self.rotate_180(),
+            self.rotate_270(),
+            flipped.rotate_90(),
+            flipped.rotate_180(),
+            flipped.rotate_270(),
+            flipped,
+        ]
+        .into_iter()
+    }
+
+    /// Panics if the rectangle doesn't fit inside the grid
+    pub fn crop(&self, top_left: Vector<2>, width: usize, height: usize) -> Self {
+        Self::from_fn(width, height, |point| self[top_left + point].clone())
+    }
+
+    /// Cuts the grid into `size` by `size` tiles, row by row.
+    ///
+    /// Panics unless both sides are a multiple of `size`.
+    pub fn tiles(&self, size: usize) -> Vec<Self> {
+        assert!(size > 0, "Tiles can't be empty");
+        assert!(
+            self.width.is_multiple_of(size) && self.height.is_multiple_of(size),
+            "A {}x{} grid can't be cut into {size}x{size} tiles",
+            self.width,
+            self.height
+        );
+        (0..self.height / size)
+            .flat_map(|y| (0..self.width / size).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                let top_left = Vector::new([(x * size) as i32, (y * size) as i32]);
+                self.crop(top_left, size, size)
+            })
+            .collect()
+    }
+
+    /// Puts equally sized tiles back together, the reverse of [`DenseGrid::tiles`].
+    ///
+    /// `None` if there are no tiles, they aren't all the same size or the last row isn't full.
+    pub fn stitch(tiles: &[Self], tiles_per_row: usize) -> Option<Self> {
+        let (width, height) = tiles.first().map(|tile| (tile.width, tile.height))?;
+        let same_size = tiles
+            .iter()
+            .all(|tile| (tile.width, tile.height) == (width, height));
+        if !same_size || tiles_per_row == 0 || !tiles.len().is_multiple_of(tiles_per_row) {
+            return None;
+        }
+
+        Some(Self::from_xy(
+            width * tiles_per_row,
+            height * (tiles.len() / tiles_per_row),
+            |x, y| tiles[(y / height) * tiles_per_row + x / width].at(x % width, y % height),
+        ))
+    }
+}
+
+impl<T> Index<Vector<2>> for DenseGrid<T> {
+    type Output = T;
+
+    fn index(&self, point: Vector<2>) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Vector<2>> for DenseGrid<T> {
+    fn index_mut(&mut self, point: Vector<2>) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+
+impl<T> From<DenseGrid<T>> for Grid<T> {
+    fn from(grid: DenseGrid<T>) -> Self {
+        let points = grid.keys().collect::<Vec<_>>();
+        Grid::new(points.into_iter().zip(grid.cells).collect())
+    }
+}
+
+/// Covers `(0, 0)` up to the largest coordinates in the sparse grid; points it doesn't have
+/// become `T::default()`. Fails with the offending point if one has a negative coordinate.
+impl<T: Default> TryFrom<Grid<T>> for DenseGrid<T> {
+    type Error = Vector<2>;
+
+    fn try_from(grid: Grid<T>) -> Result<Self, Self::Error> {
+        if let Some(point) = grid.keys().find(|point| point[0] < 0 || point[1] < 0) {
+            return Err(point);
+        }
+        let width = grid
+            .keys()
+            .map(|point| point[0] as usize + 1)
+            .max()
+            .unwrap_or(0);
+        let height = grid
+            .keys()
+            .map(|point| point[1] as usize + 1)
+            .max()
+            .unwrap_or(0);
+
+        let mut dense = Self::new(
+            width,
+            height,
+            (0..width * height).map(|_| T::default()).collect(),
+        );
+        for (point, value) in grid.inner {
+            dense[point] = value;
+        }
+        Ok(dense)
+    }
+}
+
+impl<T> Search for DenseGrid<T> {
+    type Cell = T;
+
+    fn cell(&self, point: Vector<2>) -> Option<&T> {
+        self.get(point)
+    }
+
+    fn points(&self) -> impl Iterator<Item = Vector<2>> {
+        self.keys()
+    }
+}
+
+// Breadth-first search through passable points, stopping early once `goal` is reached. Maps
+// every point reached to its distance from `start` and the point it was reached from.
+fn bfs(
+    start: Vector<2>,
+    goal: Option<Vector<2>>,
+    neighbourhood: Neighbourhood,
+    passable: impl Fn(Vector<2>) -> bool,
+) -> HashMap<Vector<2>, (usize, Vector<2>)> {
+    let mut reached = HashMap::from([(start, (0, start))]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(point) = queue.pop_front() {
+        if Some(point) == goal {
+            break;
+        }
+        let distance = reached[&point].0 + 1;
+        for neighbour in neighbourhood.of(point) {
+            if !reached.contains_key(&neighbour) && passable(neighbour) {
+                reached.insert(neighbour, (distance, point));
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    reached
+}
+
+fn distances(reached: HashMap<Vector<2>, (usize, Vector<2>)>) -> HashMap<Vector<2>, usize> {
+    reached
+        .into_iter()
+        .map(|(point, (distance, _))| (point, distance))
+        .collect()
+}
+
+fn path(
+    reached: &HashMap<Vector<2>, (usize, Vector<2>)>,
+    start: Vector<2>,
+    goal: Vector<2>,
+) -> Option<Vec<Vector<2>>> {
+    reached.get(&goal)?;
+    let mut path = vec![goal];
+    while path[path.len() - 1] != start {
+        path.push(reached[&path[path.len() - 1]].1);
+    }
+    path.reverse();
+    Some(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "#..\n.#.\n..#\n#.#";
+
+    #[test]
+    fn test_dense_grid() {
+        let mut grid = DenseGrid::parse(MAP, |c| c == '#');
+        assert_eq!((grid.width(), grid.height()), (3, 4));
+        assert_eq!(grid.get(Vector::new([2, 2])), Some(&true));
+        assert_eq!(grid.get(Vector::new([1, 2])), Some(&false));
+        assert_eq!(grid.get(Vector::new([3, 0])), None);
+        assert_eq!(grid.get(Vector::new([0, -1])), None);
+        assert_eq!(grid.values().filter(|x| **x).count(), 5);
+        assert_eq!(grid.rows().nth(3), Some([true, false, true].as_slice()));
+
+        let mut corner = grid.neighbours(Vector::new([0, 0])).collect::<Vec<_>>();
+        corner.sort_by_key(|point| (point[0], point[1]));
+        assert_eq!(corner, [Vector::new([0, 1]), Vector::new([1, 0])]);
+        assert_eq!(grid.neighbours_diagonals(Vector::new([1, 1])).count(), 8);
+        assert_eq!(grid.neighbours_diagonals(Vector::new([2, 3])).count(), 3);
+
+        grid.tick(|point, value| *value != (point[0] == 0));
+        assert!(!grid[Vector::new([0, 0])]);
+        assert!(grid[Vector::new([0, 1])]);
+        assert!(grid[Vector::new([1, 1])]);
+    }
+
+    #[test]
+    fn test_render() {
+        let mut grid = Grid::new(HashMap::new());
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid.to_string(), "");
+
+        grid.insert(Vector::new([-1, -2]), true);
+        grid.insert(Vector::new([1, 0]), false);
+        grid.insert(Vector::new([0, -1]), true);
+        assert_eq!(
+            grid.bounds(),
+            Some((Vector::new([-1, -2]), Vector::new([1, 0])))
+        );
+        assert_eq!(grid.to_string(), "#  \n # \n  .");
+        assert_eq!(
+            grid.render('?', |x| if *x { 'X' } else { 'O' }),
+            "X??\n?X?\n??O"
+        );
+
+        let dense = DenseGrid::parse(MAP, |c| c == '#');
+        assert_eq!(dense.to_string(), MAP);
+        assert_eq!(DenseGrid::parse("", |c| c).to_string(), "");
+    }
+
+    fn numbered(width: usize, height: usize) -> DenseGrid<usize> {
+        DenseGrid::new(width, height, (0..width * height).collect())
+    }
+
+    #[test]
+    fn test_rotate() {
+        let start = numbered(4, 4);
+
+        let rotate_90 = start.rotate_90();
+        assert_eq!(
+            rotate_90.rows().collect::<Vec<_>>(),
+            [[12, 8, 4, 0], [13, 9, 5, 1], [14, 10, 6, 2], [15, 11, 7, 3]]
+        );
+        assert_eq!(rotate_90.rotate_90(), start.rotate_180());
+        assert_eq!(rotate_90.rotate_180(), start.rotate_270());
+        assert_eq!(start.rotate_270().rotate_90(), start);
+        assert_eq!(
+            start.rotate_270().rows().collect::<Vec<_>>(),
+            [[3, 7, 11, 15], [2, 6, 10, 14], [1, 5, 9, 13], [0, 4, 8, 12]]
+        );
+
+        let wide = numbered(3, 2);
+        assert_eq!(
+            wide.rotate_90().rows().collect::<Vec<_>>(),
+            [[3, 0], [4, 1], [5, 2]]
+        );
+        assert_eq!(
+            wide.transpose().rows().collect::<Vec<_>>(),
+            [[0, 3], [1, 4], [2, 5]]
+        );
+    }
+
+    #[test]
+    fn test_flips() {
+        let start = numbered(3, 3);
+
+        assert_eq!(
+            start.flip_vertical().rows().collect::<Vec<_>>(),
+            [[6, 7, 8], [3, 4, 5], [0, 1, 2]]
+        );
+        assert_eq!(
+            start.flip_horizontal().rows().collect::<Vec<_>>(),
+            [[2, 1, 0], [5, 4, 3], [8, 7, 6]]
+        );
+
+        let symmetries = start.symmetries().collect::<Vec<_>>();
+        assert_eq!(symmetries.len(), 8);
+        assert!(symmetries.iter().all_unique());
+        assert!(symmetries.contains(&start.transpose()));
+        assert!(symmetries.contains(&start.flip_vertical()));
+    }
+
+    #[test]
+    fn test_tiles() {
+        let start = numbered(6, 4);
+
+        assert_eq!(
+            start
+                .crop(Vector::new([1, 2]), 3, 2)
+                .rows()
+                .collect::<Vec<_>>(),
+            [[13, 14, 15], [19, 20, 21]]
+        );
+
+        let tiles = start.tiles(2);
+        assert_eq!(
+            tiles
+                .iter()
+                .map(|tile| tile.rows().collect::<Vec<_>>())
+                .collect::<Vec<_>>(),
+            [
+                [[0, 1], [6, 7]],
+                [[2, 3], [8, 9]],
+                [[4, 5], [10, 11]],
+                [[12, 13], [18, 19]],
+                [[14, 15], [20, 21]],
+                [[16, 17], [22, 23]],
+            ]
+        );
+        assert_eq!(DenseGrid::stitch(&tiles, 3), Some(start));
+        assert_eq!(
+            DenseGrid::stitch(&tiles, 2).map(|grid| grid.width()),
+            Some(4)
+        );
+
+        assert_eq!(DenseGrid::<usize>::stitch(&[], 1), None);
+        assert_eq!(DenseGrid::stitch(&tiles, 4), None);
+        assert_eq!(DenseGrid::stitch(&tiles, 0), None);
+        let mixed = [
+            numbered(2, 2),
+            numbered(2, 2),
+            numbered(3, 2),
+            numbered(2, 2),
+        ];
+        assert_eq!(DenseGrid::stitch(&mixed, 2), None);
+    }
+
+    #[test]
+    fn test_sparse_transforms() {
+        // a gap at (1, 1), with the bounds from (-1, 0) to (1, 1)
+        let mut grid = Grid::new(HashMap::new());
+        for (point, value) in [([-1, 0], 'a'), ([0, 0], 'b'), ([1, 0], 'c'), ([-1, 1], 'd')] {
+            grid.insert(Vector::new(point), value);
+        }
+        assert_eq!(grid.to_string(), "abc\nd  ");
+        assert_eq!(grid.rotate_90().to_string(), "da\n b\n c");
+        assert_eq!(grid.rotate_90().bounds().unwrap().0, Vector::new([-1, 0]));
+        assert_eq!(grid.rotate_180().to_string(), "  d\ncba");
+        assert_eq!(grid.rotate_270().to_string(), "c \nb \nad");
+        assert_eq!(grid.transpose().to_string(), "ad\nb \nc ");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\n  d");
+        assert_eq!(grid.flip_vertical().to_string(), "d  \nabc");
+        assert_eq!(
+            grid.symmetries()
+                .map(|grid| grid.to_string())
+                .unique()
+                .count(),
+            8
+        );
+
+        let cropped = grid.crop(Vector::new([0, 0]), 5, 5);
+        assert_eq!(cropped.to_string(), "bc");
+        assert_eq!(cropped.get(Vector::new([0, 0])), Some(&'b'));
+
+        let tiles = grid.tiles(2);
+        assert_eq!(
+            tiles
+                .iter()
+                .map(|tile| tile.to_string())
+                .collect::<Vec<_>>(),
+            ["ab\nd ", "c"]
+        );
+        assert_eq!(tiles[1].get(Vector::new([0, 0])), Some(&'c'));
+        let stitched = Grid::stitch(&tiles, 2, 2).unwrap();
+        assert_eq!(stitched.to_string(), grid.to_string());
+        assert_eq!(stitched.get(Vector::new([0, 0])), Some(&'a'));
+        assert!(Grid::stitch(&tiles, 2, 1).is_none());
+        assert!(Grid::stitch(&tiles, 3, 2).is_none());
+        assert!(Grid::new(HashMap::<_, char>::new()).tiles(3).is_empty());
+    }
+
+    const MAZE: &str = "\
+#######
+#S..#.#
+#.#.#.#
+#.#...#
+#..#G##
+#######";
+
+    #[test]
+    fn test_search() {
+        let maze = Grid::parse(MAZE, |c| c);
+        let open = |c: &char| *c != '#';
+        let start = Vector::new([1, 1]);
+        let goal = Vector::new([4, 4]);
+
+        let distances = maze.bfs_distances(start, Neighbourhood::VonNeumann, open);
+        assert_eq!(distances[&start], 0);
+        assert_eq!(distances[&goal], 6);
+        assert_eq!(distances[&Vector::new([5, 1])], 8);
+        assert_eq!(distances.get(&Vector::new([0, 0])), None);
+        assert_eq!(distances.len(), 14);
+        assert_eq!(
+            maze.bfs_distances(start, Neighbourhood::Moore, open)[&goal],
+            4
+        );
+
+        let path = maze
+            .shortest_path(start, goal, Neighbourhood::VonNeumann, open)
+            .unwrap();
+        assert_eq!(path.len(), 7);
+        assert_eq!((path[0], path[6]), (start, goal));
+        assert!(path
+            .iter()
+            .tuple_windows()
+            .all(|(a, b)| a.manhattan_distance(*b) == 1));
+        assert_eq!(
+            maze.shortest_path(start, Vector::new([0, 0]), Neighbourhood::VonNeumann, open),
+            None
+        );
+
+        let dense = DenseGrid::parse(MAZE, |c| c);
+        assert_eq!(
+            dense.bfs_distances(start, Neighbourhood::VonNeumann, open),
+            distances
+        );
+        assert_eq!(
+            dense.shortest_path(start, start, Neighbourhood::Moore, open),
+            Some(vec![start])
+        );
+    }
+
+    #[test]
+    fn test_regions() {
+        let maze = DenseGrid::parse(MAZE, |c| c);
+        let wall = |c: &char| *c == '#';
+
+        assert_eq!(
+            maze.flood_fill(Vector::new([0, 0]), Neighbourhood::VonNeumann, wall)
+                .len(),
+            26
+        );
+        assert!(maze
+            .flood_fill(Vector::new([1, 1]), Neighbourhood::VonNeumann, wall)
+            .is_empty());
+
+        let mut sizes = maze
+            .connected_components(Neighbourhood::VonNeumann, wall)
+            .iter()
+            .map(HashSet::len)
+            .collect::<Vec<_>>();
+        sizes.sort();
+        // the two walls in the middle only touch the rest diagonally
+        assert_eq!(sizes, [2, 26]);
+        assert_eq!(
+            Grid::parse(MAZE, |c| c)
+                .connected_components(Neighbourhood::Moore, wall)
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_conversion() {
+        let dense = DenseGrid::parse(MAP, |c| c == '#');
+        let sparse = Grid::from(dense.clone());
+        assert_eq!(sparse.get(Vector::new([2, 3])), Some(&true));
+        assert_eq!(DenseGrid::try_from(sparse).unwrap(), dense);
+
+        let mut sparse = Grid::new(HashMap::new());
+        sparse.insert(Vector::new([2, 1]), 7);
+        let dense = DenseGrid::try_from(sparse.clone()).unwrap();
+        assert_eq!((dense.width(), dense.height()), (3, 2));
+        assert_eq!(dense.values().sum::<i32>(), 7);
+
+        sparse.insert(Vector::new([-1, 0]), 1);
+        assert_eq!(DenseGrid::try_from(sparse), Err(Vector::new([-1, 0])));
+    }
 }
@@ -0,0 +1,180 @@
+//! Hexagonal grids in axial coordinates.
+//!
+//! A hex is a [`Vector<2>`] `(q, r)`, with the third cube coordinate `s = -q - r` left out, so
+//! hexes can go in a [`Grid`](crate::grid::Grid) or an
+//! [`ActiveSet`](crate::automaton::ActiveSet) like any other point, using
+//! [`Neighbourhood::HEX`](crate::vector::Neighbourhood::HEX) for what's next to what.
+
+use crate::vector::Vector;
+use itertools::Either;
+use std::iter;
+
+/// The steps to the six neighbouring hexes, anticlockwise starting along `q`
+pub const DIRECTIONS: [Vector<2>; 6] = [
+    Vector::new([1, 0]),
+    Vector::new([1, -1]),
+    Vector::new([0, -1]),
+    Vector::new([-1, 0]),
+    Vector::new([-1, 1]),
+    Vector::new([0, 1]),
+];
+
+/// Which way up the hexes sit, which decides the compass names of the six steps
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Orientation {
+    /// Columns of hexes with flat tops, stepping `n`, `ne`, `se`, `s`, `sw` or `nw`
+    FlatTop,
+    /// Rows of hexes with pointy tops, stepping `e`, `ne`, `nw`, `w`, `sw` or `se`
+    PointyTop,
+}
+
+impl Orientation {
+    /// The step with the given compass name
+    pub fn step(self, name: &str) -> Result<Vector<2>, String> {
+        let index = match (self, name) {
+            (Self::FlatTop, "se") | (Self::PointyTop, "e") => 0,
+            (_, "ne") => 1,
+            (Self::FlatTop, "n") | (Self::PointyTop, "nw") => 2,
+            (Self::FlatTop, "nw") | (Self::PointyTop, "w") => 3,
+            (_, "sw") => 4,
+            (Self::FlatTop, "s") | (Self::PointyTop, "se") => 5,
+            _ => return Err(format!("Invalid {self:?} step: {name:?}")),
+        };
+        Ok(DIRECTIONS[index])
+    }
+
+    /// Steps either separated by commas, like `ne,ne,s`, or run together, like `nwwswee`
+    pub fn parse_steps(self, s: &str) -> Result<Vec<Vector<2>>, String> {
+        let mut steps = Vec::new();
+        let mut rest = s.trim().trim_start_matches(',');
+        while let Some(first) = rest.chars().next() {
+            // a two letter step starts like a one letter one, so try the longer first
+            let length = match rest.get(..2) {
+                Some(name) if self.step(name).is_ok() => 2,
+                _ => first.len_utf8(),
+            };
+            let (name, tail) = rest.split_at(length);
+            steps.push(self.step(name)?);
+            rest = tail.trim_start_matches(',');
+        }
+        Ok(steps)
+    }
+}
+
+/// The full cube coordinates `(q, r, s)`, which always add up to 0
+pub fn cube(hex: Vector<2>) -> Vector<3> {
+    Vector::new([hex[0], hex[1], -hex[0] - hex[1]])
+}
+
+/// The inverse of [`cube`]
+pub fn from_cube(cube: Vector<3>) -> Vector<2> {
+    debug_assert_eq!(
+        cube.iter().sum::<i32>(),
+        0,
+        "Not a cube coordinate: {cube:?}"
+    );
+    Vector::new([cube[0], cube[1]])
+}
+
+/// The number of steps between two hexes
+pub fn distance(a: Vector<2>, b: Vector<2>) -> u32 {
+    cube(a - b).chebyshev_distance(Vector::ZERO)
+}
+
+pub fn neighbours(hex: Vector<2>) -> impl Iterator<Item = Vector<2>> {
+    DIRECTIONS.into_iter().map(move |direction| hex + direction)
+}
+
+/// Every hex exactly `radius` steps from `center`, going round anticlockwise, which is just
+/// `center` for a radius of 0
+pub fn ring(center: Vector<2>, radius: u32) -> impl Iterator<Item = Vector<2>> {
+    if radius == 0 {
+        return Either::Left(iter::once(center));
+    }
+    let mut hex = center + DIRECTIONS[4] * i32::try_from(radius).expect("Radius too big");
+    let steps = DIRECTIONS
+        .into_iter()
+        .flat_map(move |direction| iter::repeat_n(direction, radius as usize));
+    Either::Right(steps.map(move |step| {
+        let current = hex;
+        hex += step;
+        current
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::automaton::{life, ActiveSet};
+    use crate::grid::{Grid, Search};
+    use crate::vector::Neighbourhood;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_steps() {
+        let walk = |orientation: Orientation, s| {
+            orientation
+                .parse_steps(s)
+                .unwrap()
+                .into_iter()
+                .fold(Vector::ZERO, |hex, step| hex + step)
+        };
+        let flat = |s| distance(Vector::ZERO, walk(Orientation::FlatTop, s));
+        assert_eq!(flat("ne,ne,ne"), 3);
+        assert_eq!(flat("ne,ne,sw,sw"), 0);
+        assert_eq!(flat("ne,ne,s,s"), 2);
+        assert_eq!(flat("se,sw,se,sw,sw"), 3);
+
+        let pointy = Orientation::PointyTop;
+        assert_eq!(walk(pointy, "nwwswee"), Vector::ZERO);
+        assert_eq!(walk(pointy, "esew"), pointy.step("se").unwrap());
+        assert_eq!(pointy.parse_steps("nene").unwrap().len(), 2);
+        assert!(pointy.parse_steps("n").is_err());
+        assert!(Orientation::FlatTop.parse_steps("e").is_err());
+
+        for orientation in [Orientation::FlatTop, Orientation::PointyTop] {
+            let steps = orientation.parse_steps("ne,nw,sw,se").unwrap();
+            assert!(steps.iter().all(|step| DIRECTIONS.contains(step)));
+        }
+    }
+
+    #[test]
+    fn test_coordinates() {
+        let hex = Vector::new([2, -5]);
+        assert_eq!(cube(hex), Vector::new([2, -5, 3]));
+        assert_eq!(from_cube(cube(hex)), hex);
+        assert_eq!(distance(hex, Vector::ZERO), 5);
+        assert!(neighbours(hex).all(|neighbour| distance(hex, neighbour) == 1));
+    }
+
+    #[test]
+    fn test_ring() {
+        let center = Vector::new([3, 1]);
+        assert_eq!(ring(center, 0).collect::<Vec<_>>(), [center]);
+        for radius in 1..5 {
+            let hexes = ring(center, radius).collect::<HashSet<_>>();
+            assert_eq!(hexes.len(), 6 * radius as usize);
+            assert!(hexes.iter().all(|hex| distance(center, *hex) == radius));
+        }
+        assert_eq!(
+            ring(center, 1).collect::<HashSet<_>>(),
+            neighbours(center).collect()
+        );
+    }
+
+    #[test]
+    fn test_neighbourhood() {
+        let hexes = (0..4).flat_map(|radius| ring(Vector::ZERO, radius));
+        let grid = Grid::new(hexes.map(|hex| (hex, ())).collect());
+        let distances = grid.bfs_distances(Vector::ZERO, Neighbourhood::HEX, |_| true);
+        assert_eq!(distances.len(), 1 + 6 + 12 + 18);
+        assert!(distances
+            .iter()
+            .all(|(hex, steps)| distance(*hex, Vector::ZERO) as usize == *steps));
+
+        // each neighbour of a single live hex has only it as a live neighbour
+        let mut world = ActiveSet::new([Vector::ZERO], Neighbourhood::HEX);
+        world.step(life(&[1], &[]));
+        assert_eq!(world.live(), &neighbours(Vector::ZERO).collect());
+    }
+}
@@ -1,49 +1,61 @@
+pub mod automaton;
+pub mod cache;
+pub mod client;
+pub mod cycle;
+pub mod direction;
+mod error;
+mod examples;
 pub mod grid;
+pub mod hex;
+#[cfg(any(test, feature = "mock-server"))]
+pub mod mock_server;
 pub mod paragraphs;
+pub mod solution;
+mod submit;
 pub mod vector;
 
 use dotenv::dotenv;
 use lazy_static::lazy_static;
-use reqwest::{
-    blocking::{Client, ClientBuilder},
-    header,
-};
-use std::{env, path::PathBuf};
+use std::{fmt, path::PathBuf};
+
+use crate::client::AocClient;
+pub use crate::error::AocError;
+pub use crate::examples::{parse_examples, parse_title, Example};
+pub use crate::submit::Outcome;
 
 lazy_static! {
-    static ref ENVIRONMENT: Result<PathBuf, dotenv::Error> = { dotenv() };
+    static ref ENVIRONMENT: Result<PathBuf, dotenv::Error> = dotenv();
 }
 
-fn get_token_from_env() -> Result<String, env::VarError> {
-    let _ = ENVIRONMENT.as_ref().ok();
-    env::var("AOC_TOKEN")
+/// Returns the input for the given puzzle, served from the on-disk cache when possible.
+///
+/// Set `AOC_OFFLINE=1` to never contact adventofcode.com; missing inputs then fail with
+/// [`AocError::NotCached`] instead of a network error.
+pub fn try_get_input_year(year: usize, day: usize) -> Result<String, AocError> {
+    AocClient::from_env().input(year, day)
 }
 
-fn get_client() -> Client {
-    let mut headers = header::HeaderMap::new();
-    headers.insert(
-        "Cookie",
-        header::HeaderValue::from_str(
-            &get_token_from_env()
-                .expect("AOC_TOKEN must be specified as environment variable or in .env"),
-        )
-        .unwrap(),
-    );
-    ClientBuilder::new()
-        .default_headers(headers)
-        .build()
-        .unwrap()
+/// Like [`try_get_input_year`], but panics with the error message on failure
+pub fn get_input_year(year: usize, day: usize) -> String {
+    try_get_input_year(year, day).unwrap_or_else(|e| panic!("{e}"))
 }
 
-pub fn get_input_year(year: usize, day: usize) -> String {
-    let url = format!("https://adventofcode.com/{year}/day/{day}/input");
-    let x = get_client().get(url).send().unwrap();
-    if !x.status().is_success() {
-        panic!(
-            "Request error: {}: {}",
-            x.status().as_str(),
-            x.text().unwrap().trim()
-        )
-    }
-    x.text().unwrap().trim().to_string()
+/// See [`AocClient::submit`]
+pub fn submit_answer(
+    year: usize,
+    day: usize,
+    part: usize,
+    answer: impl fmt::Display,
+) -> Result<Outcome, AocError> {
+    AocClient::from_env().submit(year, day, part, answer)
+}
+
+/// Examples from the puzzle text, for use in tests. See [`AocClient::examples`].
+pub fn try_examples(year: usize, day: usize) -> Result<Vec<Example>, AocError> {
+    AocClient::from_env().examples(year, day)
+}
+
+/// Like [`try_examples`], but panics with the error message on failure
+pub fn examples(year: usize, day: usize) -> Vec<Example> {
+    try_examples(year, day).unwrap_or_else(|e| panic!("{e}"))
 }
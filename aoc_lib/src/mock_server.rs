@@ -0,0 +1,189 @@
+//! Minimal stand-in for adventofcode.com, for exercising [`crate::client::AocClient`] without a
+//! network. Enabled in this crate's tests, and for other crates with the `mock-server` feature.
+
+use std::{
+    collections::HashMap,
+    io::{BufRead, BufReader, Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+#[derive(Clone, Debug)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub path: String,
+    pub cookie: Option<String>,
+    pub body: String,
+}
+
+#[derive(Default)]
+struct State {
+    routes: HashMap<(String, String), (u16, String)>,
+    requests: Vec<RecordedRequest>,
+}
+
+/// Serves canned responses keyed on method and path; anything unknown gets a 404.
+/// The server shuts down when dropped.
+pub struct MockServer {
+    addr: SocketAddr,
+    state: Arc<Mutex<State>>,
+    shutdown: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl MockServer {
+    pub fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Could not bind mock server");
+        let addr = listener.local_addr().unwrap();
+        let state = Arc::new(Mutex::new(State::default()));
+        let shutdown = Arc::new(AtomicBool::new(false));
+
+        let handle = {
+            let state = state.clone();
+            let shutdown = shutdown.clone();
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    if shutdown.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    if let Ok(stream) = stream {
+                        let _ = handle_connection(stream, &state);
+                    }
+                }
+            })
+        };
+
+        Self {
+            addr,
+            state,
+            shutdown,
+            handle: Some(handle),
+        }
+    }
+
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    pub fn status(&self, method: &str, path: &str, status: u16, body: &str) -> &Self {
+        self.state.lock().unwrap().routes.insert(
+            (method.to_string(), path.to_string()),
+            (status, body.to_string()),
+        );
+        self
+    }
+
+    pub fn input(&self, year: usize, day: usize, input: &str) -> &Self {
+        self.status("GET", &format!("/{year}/day/{day}/input"), 200, input)
+    }
+
+    pub fn puzzle(&self, year: usize, day: usize, html: &str) -> &Self {
+        self.status("GET", &format!("/{year}/day/{day}"), 200, html)
+    }
+
+    /// Responds to answer submissions for the day with the given page
+    pub fn answer(&self, year: usize, day: usize, html: &str) -> &Self {
+        self.status("POST", &format!("/{year}/day/{day}/answer"), 200, html)
+    }
+
+    pub fn rate_limited(&self, year: usize, day: usize, wait: Duration) -> &Self {
+        let wait = wait.as_secs();
+        let wait = match wait / 60 {
+            0 => format!("{wait}s"),
+            minutes => format!("{minutes}m {}s", wait % 60),
+        };
+        self.answer(
+            year,
+            day,
+            &format!(
+                "<article><p>You gave an answer too recently; you have to wait after submitting an \
+                 answer before trying again.  You have {wait} left to wait.</p></article>"
+            ),
+        )
+    }
+
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        // wake the accept loop so it can see the shutdown flag
+        let _ = TcpStream::connect(self.addr);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn handle_connection(stream: TcpStream, state: &Mutex<State>) -> std::io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let path = parts.next().unwrap_or_default().to_string();
+
+    let mut content_length = 0;
+    let mut cookie = None;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+            break;
+        }
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        match name.trim().to_ascii_lowercase().as_str() {
+            "content-length" => content_length = value.trim().parse().unwrap_or(0),
+            "cookie" => cookie = Some(value.trim().to_string()),
+            _ => {}
+        }
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+
+    let (status, body) = {
+        let mut state = state.lock().unwrap();
+        let response = state
+            .routes
+            .get(&(method.clone(), path.clone()))
+            .cloned()
+            .unwrap_or((404, "404 Not Found".to_string()));
+        state.requests.push(RecordedRequest {
+            method,
+            path,
+            cookie,
+            body: String::from_utf8_lossy(&body).into_owned(),
+        });
+        response
+    };
+
+    let mut stream = stream;
+    write!(
+        stream,
+        "HTTP/1.1 {status} {}\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        reason(status),
+        body.len()
+    )?;
+    stream.flush()
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        500 => "Internal Server Error",
+        _ => "Unknown",
+    }
+}
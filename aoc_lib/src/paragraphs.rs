@@ -6,9 +6,9 @@ pub trait Paragraphs {
 
 impl<T> Paragraphs for T
 where
-    T: AsRef<str>,
+    T: AsRef<str> + ?Sized,
 {
     fn paragraphs(&self) -> impl Iterator<Item = Lines<'_>> {
-        self.as_ref().split("\n\n").into_iter().map(|s| s.lines())
+        self.as_ref().split("\n\n").map(|s| s.lines())
     }
 }
@@ -0,0 +1,133 @@
+use std::{
+    fmt::Display,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::get_input_year;
+
+/// A day's puzzle: how to turn the input into something both parts can work from, and how to
+/// solve each part. The parsed form may borrow from the input.
+pub trait Solution {
+    const YEAR: usize;
+    const DAY: usize;
+    const TITLE: &'static str;
+
+    type Parsed<'a>;
+    type Part1: Answer;
+    type Part2: Answer;
+
+    fn parse(input: &str) -> Self::Parsed<'_>;
+    fn part_1(parsed: &Self::Parsed<'_>) -> Self::Part1;
+    fn part_2(parsed: &Self::Parsed<'_>) -> Self::Part2;
+}
+
+/// Anything that can be given as an answer; `None` means the part has nothing to submit
+pub trait Answer {
+    fn to_answer(&self) -> Option<String>;
+}
+
+impl<T: Display> Answer for T {
+    fn to_answer(&self) -> Option<String> {
+        Some(self.to_string())
+    }
+}
+
+/// For parts without a puzzle of their own, like the second half of day 25
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct NoAnswer;
+
+impl Answer for NoAnswer {
+    fn to_answer(&self) -> Option<String> {
+        None
+    }
+}
+
+/// A [`Solution`] with its types erased, so days from every year can share one registry.
+///
+/// Implemented for every `Solution`; each year lists its days in `days::DAYS`.
+pub trait DynSolution: Sync {
+    fn year(&self) -> usize;
+    fn day(&self) -> usize;
+    fn title(&self) -> &'static str;
+
+    /// Parses the input once and solves the given parts in order, handing each answer over as
+    /// soon as it's known
+    fn solve(&self, input: &str, parts: &[usize], on_answer: &mut dyn FnMut(usize, Option<String>));
+
+    /// Runs parse, part 1 and part 2 once each and returns how long each took
+    fn time(&self, input: &str) -> [Duration; 3];
+}
+
+impl<S: Solution + Sync> DynSolution for S {
+    fn year(&self) -> usize {
+        S::YEAR
+    }
+
+    fn day(&self) -> usize {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn solve(
+        &self,
+        input: &str,
+        parts: &[usize],
+        on_answer: &mut dyn FnMut(usize, Option<String>),
+    ) {
+        let parsed = S::parse(input);
+        for &part in parts {
+            let answer = match part {
+                1 => S::part_1(&parsed).to_answer(),
+                2 => S::part_2(&parsed).to_answer(),
+                _ => panic!("Puzzles only have parts 1 and 2, not {part}"),
+            };
+            on_answer(part, answer);
+        }
+    }
+
+    fn time(&self, input: &str) -> [Duration; 3] {
+        let start = Instant::now();
+        let parsed = black_box(S::parse(black_box(input)));
+        let parse = start.elapsed();
+
+        let start = Instant::now();
+        black_box(S::part_1(&parsed));
+        let part_1 = start.elapsed();
+
+        let start = Instant::now();
+        black_box(S::part_2(&parsed));
+        let part_2 = start.elapsed();
+
+        [parse, part_1, part_2]
+    }
+}
+
+pub fn print_header(solution: &dyn DynSolution) {
+    println!(
+        "--- {} day {}: {} ---",
+        solution.year(),
+        solution.day(),
+        solution.title()
+    );
+}
+
+pub fn print_answer(part: usize, answer: Option<String>) {
+    match answer {
+        // rendered letters and the like read better starting on their own line
+        Some(answer) if answer.contains('\n') => println!("Part {part}:\n{answer}"),
+        Some(answer) => println!("Part {part}: {answer}"),
+        None => {}
+    }
+}
+
+/// Fetches the day's input and prints both answers
+pub fn run(solution: &dyn DynSolution) {
+    let input = get_input_year(solution.year(), solution.day());
+
+    print_header(solution);
+    solution.solve(&input, &[1, 2], &mut print_answer);
+}
@@ -0,0 +1,168 @@
+use std::{fmt, str::FromStr, time::Duration};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited { wait: Duration },
+    AlreadySolved,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "too-high"),
+            Outcome::TooLow => write!(f, "too-low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::RateLimited { wait } => write!(f, "rate-limited({}s)", wait.as_secs()),
+            Outcome::AlreadySolved => write!(f, "already-solved"),
+        }
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "correct" => Outcome::Correct,
+            "too-high" => Outcome::TooHigh,
+            "too-low" => Outcome::TooLow,
+            "wrong" => Outcome::Wrong,
+            "already-solved" => Outcome::AlreadySolved,
+            _ => return Err(()),
+        })
+    }
+}
+
+pub(crate) fn known_outcome<'a>(
+    previous: impl Iterator<Item = (Outcome, &'a str)>,
+    answer: &str,
+) -> Option<Outcome> {
+    let numeric = answer.parse::<i128>().ok();
+    for (outcome, previous_answer) in previous {
+        if previous_answer == answer {
+            return Some(outcome);
+        }
+        let (Some(answer), Ok(previous_answer)) = (numeric, previous_answer.parse::<i128>()) else {
+            continue;
+        };
+        match outcome {
+            Outcome::TooHigh if answer >= previous_answer => return Some(Outcome::TooHigh),
+            Outcome::TooLow if answer <= previous_answer => return Some(Outcome::TooLow),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Interprets the `<article>` text of the page returned after posting an answer
+pub fn parse_response(html: &str) -> Option<Outcome> {
+    if html.contains("That's the right answer") {
+        Some(Outcome::Correct)
+    } else if html.contains("You gave an answer too recently") {
+        Some(Outcome::RateLimited {
+            wait: parse_wait(html).unwrap_or_default(),
+        })
+    } else if html.contains("You don't seem to be solving the right level") {
+        Some(Outcome::AlreadySolved)
+    } else if html.contains("That's not the right answer") {
+        if html.contains("your answer is too high") {
+            Some(Outcome::TooHigh)
+        } else if html.contains("your answer is too low") {
+            Some(Outcome::TooLow)
+        } else {
+            Some(Outcome::Wrong)
+        }
+    } else {
+        None
+    }
+}
+
+// "You have 1m 23s left to wait." / "You have 45s left to wait."
+fn parse_wait(html: &str) -> Option<Duration> {
+    let start = html.find("You have ")? + "You have ".len();
+    let end = start + html[start..].find(" left to wait")?;
+    html[start..end]
+        .split_whitespace()
+        .map(|unit| {
+            let (number, suffix) = unit.split_at(unit.find(|c: char| !c.is_ascii_digit())?);
+            let number = number.parse::<u64>().ok()?;
+            match suffix {
+                "h" => Some(number * 3600),
+                "m" => Some(number * 60),
+                "s" => Some(number),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_response() {
+        let checks = [
+            (
+                "<article><p>That's the right answer!  You are one gold star closer to saving Christmas.</p></article>",
+                Outcome::Correct,
+            ),
+            (
+                "<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; please wait one minute before trying again.</p></article>",
+                Outcome::TooHigh,
+            ),
+            (
+                "<article><p>That's not the right answer; your answer is too low.</p></article>",
+                Outcome::TooLow,
+            ),
+            (
+                "<article><p>That's not the right answer.  If you're stuck, make sure you're using the full input data.</p></article>",
+                Outcome::Wrong,
+            ),
+            (
+                "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait.</p></article>",
+                Outcome::RateLimited {
+                    wait: Duration::from_secs(83),
+                },
+            ),
+            (
+                "<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>",
+                Outcome::AlreadySolved,
+            ),
+        ];
+
+        for (html, outcome) in checks {
+            assert_eq!(parse_response(html), Some(outcome), "parsing {html}");
+        }
+        assert_eq!(parse_response("<html></html>"), None);
+    }
+
+    #[test]
+    fn test_known_outcome() {
+        let previous = [
+            (Outcome::TooHigh, "500"),
+            (Outcome::TooLow, "100"),
+            (Outcome::Wrong, "abc"),
+        ];
+
+        assert_eq!(
+            known_outcome(previous.into_iter(), "abc"),
+            Some(Outcome::Wrong)
+        );
+        assert_eq!(
+            known_outcome(previous.into_iter(), "600"),
+            Some(Outcome::TooHigh)
+        );
+        assert_eq!(
+            known_outcome(previous.into_iter(), "50"),
+            Some(Outcome::TooLow)
+        );
+        assert_eq!(known_outcome(previous.into_iter(), "250"), None);
+    }
+}
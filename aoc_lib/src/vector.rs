@@ -1,118 +1,536 @@
-use itertools::Itertools;
-use std::ops::{Add, Deref, DerefMut, Mul, Sub};
+use crate::hex;
+use itertools::Either;
+use num::rational::Ratio;
+use num::traits::ops::overflowing::{OverflowingAdd, OverflowingSub};
+use num::traits::{
+    CheckedAdd, CheckedMul, CheckedSub, ConstOne, ConstZero, Euclid, NumCast, ToPrimitive,
+};
+use num::{Integer, Signed};
+use std::ops::{
+    Add, AddAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub,
+    SubAssign,
+};
+use std::{array, iter};
 
+/// The numbers a [`Vector`] can be made of: the signed integers, floats and [`num::Rational64`]
+/// and friends.
+pub trait Scalar: Copy + PartialOrd + Signed + ConstZero + ConstOne {}
+
+impl<T: Copy + PartialOrd + Signed + ConstZero + ConstOne> Scalar for T {}
+
+/// A [`Scalar`] that can say how far apart two of its values are. For the integers that's the
+/// unsigned integer of the same size, which holds the difference between any two of them.
+pub trait AbsDiff: Scalar {
+    type Distance: Copy + PartialOrd + ConstZero + ConstOne + Add<Output = Self::Distance>;
+
+    fn abs_diff(self, other: Self) -> Self::Distance;
+}
+
+macro_rules! abs_diff_unsigned {
+    ($($signed:ty => $unsigned:ty),*) => {
+        $(
+            impl AbsDiff for $signed {
+                type Distance = $unsigned;
+
+                fn abs_diff(self, other: Self) -> $unsigned {
+                    <$signed>::abs_diff(self, other)
+                }
+            }
+        )*
+    };
+}
+
+abs_diff_unsigned!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+
+macro_rules! abs_diff_signed {
+    ($($t:ty),*) => {
+        $(
+            impl AbsDiff for $t {
+                type Distance = Self;
+
+                fn abs_diff(self, other: Self) -> Self {
+                    (self - other).abs()
+                }
+            }
+        )*
+    };
+}
+
+abs_diff_signed!(f32, f64);
+
+impl<T: Copy + Integer + Signed> AbsDiff for Ratio<T>
+where
+    Ratio<T>: Scalar,
+{
+    type Distance = Self;
+
+    fn abs_diff(self, other: Self) -> Self {
+        (self - other).abs()
+    }
+}
+
+/// A point or an offset, made of `i32`s unless said otherwise, like `Vector<3, i64>`
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
-pub struct Vector<const DIMENSIONS: usize>([i32; DIMENSIONS]);
+pub struct Vector<const DIMENSIONS: usize, T = i32>([T; DIMENSIONS]);
 
-impl<const DIMENSIONS: usize> Vector<DIMENSIONS> {
-    pub fn new(vector: [i32; DIMENSIONS]) -> Self {
+impl<const DIMENSIONS: usize, T: Scalar> Vector<DIMENSIONS, T> {
+    pub const ZERO: Self = Self([T::ZERO; DIMENSIONS]);
+
+    pub const fn new(vector: [T; DIMENSIONS]) -> Self {
         Self(vector)
     }
+
+    /// One along the given axis and zero along the others
+    pub fn unit(axis: usize) -> Self {
+        let mut vector = Self::ZERO;
+        vector[axis] = T::ONE;
+        vector
+    }
+
+    /// Applies `f` to every component
+    pub fn map(self, f: impl FnMut(T) -> T) -> Self {
+        Self(self.0.map(f))
+    }
+
+    /// Combines the components of two vectors pairwise
+    pub fn zip_with(self, other: Self, mut f: impl FnMut(T, T) -> T) -> Self {
+        Self(array::from_fn(|i| f(self.0[i], other.0[i])))
+    }
+
+    /// Converts every component, or `None` if one doesn't fit in the new type
+    pub fn cast<U: Scalar + NumCast>(self) -> Option<Vector<DIMENSIONS, U>>
+    where
+        T: ToPrimitive,
+    {
+        let mut components = [U::ZERO; DIMENSIONS];
+        for (to, from) in components.iter_mut().zip(self.0) {
+            *to = U::from(from)?;
+        }
+        Some(Vector(components))
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self>
+    where
+        T: CheckedAdd,
+    {
+        self.checked_zip_with(other, |l, r| l.checked_add(&r))
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self>
+    where
+        T: CheckedSub,
+    {
+        self.checked_zip_with(other, |l, r| l.checked_sub(&r))
+    }
+
+    /// Multiplies every component by the same scalar
+    pub fn checked_mul(self, rhs: T) -> Option<Self>
+    where
+        T: CheckedMul,
+    {
+        let mut components = self.0;
+        for x in &mut components {
+            *x = x.checked_mul(&rhs)?;
+        }
+        Some(Self(components))
+    }
+
+    fn checked_zip_with(self, other: Self, f: impl Fn(T, T) -> Option<T>) -> Option<Self> {
+        let mut components = self.0;
+        for (l, r) in components.iter_mut().zip(other.0) {
+            *l = f(*l, r)?;
+        }
+        Some(Self(components))
+    }
+
+    /// The wrapped sum, along with whether any component overflowed
+    pub fn overflowing_add(self, other: Self) -> (Self, bool)
+    where
+        T: OverflowingAdd,
+    {
+        self.overflowing_zip_with(other, |l, r| l.overflowing_add(&r))
+    }
+
+    /// The wrapped difference, along with whether any component overflowed
+    pub fn overflowing_sub(self, other: Self) -> (Self, bool)
+    where
+        T: OverflowingSub,
+    {
+        self.overflowing_zip_with(other, |l, r| l.overflowing_sub(&r))
+    }
+
+    fn overflowing_zip_with(self, other: Self, f: impl Fn(T, T) -> (T, bool)) -> (Self, bool) {
+        let mut overflowed = false;
+        let vector = self.zip_with(other, |l, r| {
+            let (x, overflow) = f(l, r);
+            overflowed |= overflow;
+            x
+        });
+        (vector, overflowed)
+    }
 }
 
-impl<const DIMENSIONS: usize> Deref for Vector<DIMENSIONS> {
-    type Target = [i32; DIMENSIONS];
+impl<const DIMENSIONS: usize, T> Deref for Vector<DIMENSIONS, T> {
+    type Target = [T; DIMENSIONS];
 
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
 
-impl<const DIMENSIONS: usize> DerefMut for Vector<DIMENSIONS> {
+impl<const DIMENSIONS: usize, T> DerefMut for Vector<DIMENSIONS, T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.0
     }
 }
 
-impl<const DIMENSIONS: usize> Default for Vector<DIMENSIONS> {
+impl<const DIMENSIONS: usize, T: Scalar> Default for Vector<DIMENSIONS, T> {
     fn default() -> Self {
-        Vector([0; DIMENSIONS])
+        Self::ZERO
     }
 }
 
-impl<const DIMENSIONS: usize> Add for Vector<DIMENSIONS> {
-    type Output = Vector<DIMENSIONS>;
+/// Implements an operator componentwise, both between two vectors and with the same scalar for
+/// every component, along with its assigning version
+macro_rules! componentwise {
+    ($op:ident, $method:ident, $op_assign:ident, $method_assign:ident) => {
+        impl<const DIMENSIONS: usize, T: Scalar> $op for Vector<DIMENSIONS, T> {
+            type Output = Self;
 
-    fn add(self, rhs: Self) -> Self::Output {
-        Vector(
-            self.0
-                .into_iter()
-                .zip(rhs.0)
-                .map(|(l, r)| l + r)
-                .collect_vec()
-                .try_into()
-                .unwrap(),
-        )
-    }
-}
+            fn $method(self, rhs: Self) -> Self {
+                self.zip_with(rhs, $op::$method)
+            }
+        }
 
-impl<const DIMENSIONS: usize> Sub for Vector<DIMENSIONS> {
-    type Output = Vector<DIMENSIONS>;
+        impl<const DIMENSIONS: usize, T: Scalar> $op<T> for Vector<DIMENSIONS, T> {
+            type Output = Self;
 
-    fn sub(self, rhs: Self) -> Self::Output {
-        Vector(
-            self.0
-                .into_iter()
-                .zip(rhs.0)
-                .map(|(l, r)| l - r)
-                .collect_vec()
-                .try_into()
-                .unwrap(),
-        )
-    }
-}
+            fn $method(self, rhs: T) -> Self {
+                self.map(|x| $op::$method(x, rhs))
+            }
+        }
 
-impl<const DIMENSIONS: usize> Mul for Vector<DIMENSIONS> {
-    type Output = Vector<DIMENSIONS>;
+        impl<const DIMENSIONS: usize, T: Scalar> $op_assign for Vector<DIMENSIONS, T> {
+            fn $method_assign(&mut self, rhs: Self) {
+                *self = $op::$method(*self, rhs);
+            }
+        }
 
-    fn mul(self, rhs: Self) -> Self::Output {
-        Vector(
-            self.0
-                .into_iter()
-                .zip(rhs.0)
-                .map(|(l, r)| l * r)
-                .collect_vec()
-                .try_into()
-                .unwrap(),
-        )
-    }
+        impl<const DIMENSIONS: usize, T: Scalar> $op_assign<T> for Vector<DIMENSIONS, T> {
+            fn $method_assign(&mut self, rhs: T) {
+                *self = $op::$method(*self, rhs);
+            }
+        }
+    };
 }
 
-impl<const DIMENSIONS: usize> Mul<i32> for Vector<DIMENSIONS> {
-    type Output = Vector<DIMENSIONS>;
+componentwise!(Add, add, AddAssign, add_assign);
+componentwise!(Sub, sub, SubAssign, sub_assign);
+componentwise!(Mul, mul, MulAssign, mul_assign);
+componentwise!(Div, div, DivAssign, div_assign);
+componentwise!(Rem, rem, RemAssign, rem_assign);
+
+impl<const DIMENSIONS: usize, T: Scalar> Neg for Vector<DIMENSIONS, T> {
+    type Output = Self;
 
-    fn mul(self, rhs: i32) -> Self::Output {
-        Vector(
-            self.0
-                .into_iter()
-                .map(|l| l * rhs)
-                .collect_vec()
-                .try_into()
-                .unwrap(),
-        )
+    fn neg(self) -> Self {
+        self.map(Neg::neg)
     }
 }
 
-impl<const DIMENSIONS: usize> Vector<DIMENSIONS> {
-    pub fn neighbours_diagonals(self) -> impl Iterator<Item = Vector<DIMENSIONS>> {
-        self.0
-            .into_iter()
-            .map(|x| (x - 1)..=(x + 1))
-            .multi_cartesian_product()
-            .map(|x| Vector::new(x.try_into().unwrap()))
+impl<const DIMENSIONS: usize, T: Scalar> Vector<DIMENSIONS, T> {
+    pub fn neighbours_diagonals(self) -> impl Iterator<Item = Self> {
+        self.neighbours_within(1)
+    }
+
+    /// Every other point at most `radius` steps away along each axis
+    pub fn neighbours_within(self, radius: usize) -> impl Iterator<Item = Self> {
+        let side = 2 * radius + 1;
+        let lowest = -(0..radius).fold(T::ZERO, |x, _| x + T::ONE);
+        let offsets = iter::successors(Some(lowest), |x| Some(*x + T::ONE))
+            .take(side)
+            .collect::<Vec<_>>();
+        // counting in base `side` from all -radius to all radius, first axis slowest
+        (0..side.pow(DIMENSIONS as u32))
+            .map(move |n| {
+                Self(array::from_fn(|axis| {
+                    let digit = n / side.pow((DIMENSIONS - 1 - axis) as u32) % side;
+                    self.0[axis] + offsets[digit]
+                }))
+            })
             .filter(move |x| *x != self)
     }
 
-    pub fn neighbours(self) -> impl Iterator<Item = Vector<DIMENSIONS>> {
-        self.neighbours_diagonals()
-            .filter(move |x| x.manhattan_distance(self) == 1)
+    pub fn neighbours(self) -> impl Iterator<Item = Self> {
+        self.neighbours_diagonals().filter(move |x| {
+            let differences = (*x - self).0;
+            differences.iter().filter(|x| **x != T::ZERO).count() == 1
+        })
+    }
+
+    /// The steps along each axis added up, see [`AbsDiff`] for the type. Each axis fits, so
+    /// only points further apart in total than that type holds can overflow.
+    pub fn manhattan_distance(&self, other: Self) -> T::Distance
+    where
+        T: AbsDiff,
+    {
+        self.0
+            .into_iter()
+            .zip(other.0)
+            .fold(T::Distance::ZERO, |sum, (l, r)| sum + l.abs_diff(r))
     }
 
-    pub fn manhattan_distance(&self, other: Self) -> u32 {
+    /// The most steps needed along any one axis, which is the number of king's moves apart.
+    /// Never overflows, see [`AbsDiff`] for the type.
+    pub fn chebyshev_distance(&self, other: Self) -> T::Distance
+    where
+        T: AbsDiff,
+    {
         self.0
             .into_iter()
             .zip(other.0)
             .map(|(l, r)| l.abs_diff(r))
-            .sum::<u32>()
+            .fold(T::Distance::ZERO, |max, x| if x > max { x } else { max })
+    }
+
+    /// The square of the straight line distance, which needs no square root
+    pub fn euclidean_squared(&self, other: Self) -> T {
+        let difference = *self - other;
+        difference.dot(difference)
+    }
+
+    pub fn dot(self, other: Self) -> T {
+        (self * other).0.into_iter().fold(T::ZERO, Add::add)
+    }
+
+    pub fn signum(self) -> Self {
+        self.map(|x| x.signum())
+    }
+
+    pub fn abs(self) -> Self {
+        self.map(|x| x.abs())
+    }
+
+    /// The smaller of each pair of components
+    pub fn min(self, other: Self) -> Self {
+        self.zip_with(other, |l, r| if r < l { r } else { l })
+    }
+
+    /// The larger of each pair of components
+    pub fn max(self, other: Self) -> Self {
+        self.zip_with(other, |l, r| if r > l { r } else { l })
+    }
+
+    /// Wraps each component into `0..rhs`, e.g. to keep a point on a map that repeats
+    pub fn rem_euclid(self, rhs: Self) -> Self
+    where
+        T: Euclid,
+    {
+        self.zip_with(rhs, |l, r| l.rem_euclid(&r))
+    }
+}
+
+impl<T: Copy> Vector<2, T> {
+    pub fn x(&self) -> T {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> T {
+        self.0[1]
+    }
+}
+
+impl<T: Scalar> Vector<2, T> {
+    /// A quarter turn round the origin, clockwise with y pointing down as on a grid
+    pub fn rotate_right(self) -> Self {
+        Self([-self.0[1], self.0[0]])
+    }
+
+    /// A quarter turn round the origin, anticlockwise with y pointing down as on a grid
+    pub fn rotate_left(self) -> Self {
+        Self([self.0[1], -self.0[0]])
+    }
+
+    /// Rotates right by the given number of quarter turns, or left if it's negative, as
+    /// [`Direction4::turn`](crate::direction::Direction4::turn) does
+    pub fn rotate(self, quarter_turns: i32) -> Self {
+        match quarter_turns.rem_euclid(4) {
+            0 => self,
+            1 => self.rotate_right(),
+            2 => -self,
+            _ => self.rotate_left(),
+        }
+    }
+}
+
+impl<T: Copy> Vector<3, T> {
+    pub fn x(&self) -> T {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> T {
+        self.0[1]
+    }
+
+    pub fn z(&self) -> T {
+        self.0[2]
+    }
+}
+
+impl<T: Scalar> Vector<3, T> {
+    pub fn cross(self, other: Self) -> Self {
+        let [a1, a2, a3] = self.0;
+        let [b1, b2, b3] = other.0;
+        Self([a2 * b3 - a3 * b2, a3 * b1 - a1 * b3, a1 * b2 - a2 * b1])
+    }
+}
+
+/// Which points count as next to each other, for points with this many dimensions
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Neighbourhood<const DIMENSIONS: usize = 2> {
+    /// Points one step away along a single axis, 4 of them in two dimensions
+    VonNeumann,
+    /// Every point touching this one, diagonals included, 8 of them in two dimensions
+    Moore,
+    /// Every point at most this many steps away along each axis, so `Moore` is `Within(1)`
+    Within(usize),
+    /// The point plus each of these steps, like [`Neighbourhood::HEX`]
+    Offsets(&'static [Vector<DIMENSIONS>]),
+}
+
+impl Neighbourhood<2> {
+    /// The 6 hexes around a hex, see [`crate::hex`]
+    pub const HEX: Self = Self::Offsets(&hex::DIRECTIONS);
+}
+
+impl<const DIMENSIONS: usize> Neighbourhood<DIMENSIONS> {
+    /// Panics if one of the [`Neighbourhood::Offsets`] doesn't fit in `T`
+    pub fn of<T: Scalar + NumCast>(
+        self,
+        point: Vector<DIMENSIONS, T>,
+    ) -> impl Iterator<Item = Vector<DIMENSIONS, T>> {
+        match self {
+            Neighbourhood::VonNeumann => Either::Left(Either::Left(point.neighbours())),
+            Neighbourhood::Moore => Either::Left(Either::Right(point.neighbours_within(1))),
+            Neighbourhood::Within(radius) => {
+                Either::Left(Either::Right(point.neighbours_within(radius)))
+            }
+            Neighbourhood::Offsets(offsets) => Either::Right(offsets.iter().map(move |offset| {
+                point + offset.cast().expect("Offset too big for the point's type")
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num::Rational64;
+
+    #[test]
+    fn test_ops() {
+        let a = Vector::new([3, -4]);
+        let b = Vector::new([2, 5]);
+        assert_eq!(a + b, Vector::new([5, 1]));
+        assert_eq!(a - b, Vector::new([1, -9]));
+        assert_eq!(a * b, Vector::new([6, -20]));
+        assert_eq!(a * 2, Vector::new([6, -8]));
+        assert_eq!(a / 2, Vector::new([1, -2]));
+        assert_eq!(a % 2, Vector::new([1, 0]));
+        assert_eq!(-a, Vector::new([-3, 4]));
+        assert_eq!(a.rem_euclid(Vector::new([2, 3])), Vector::new([1, 2]));
+
+        let mut c = a;
+        c += b;
+        c -= Vector::unit(1);
+        c *= 3;
+        assert_eq!(c, Vector::new([15, 0]));
+    }
+
+    #[test]
+    fn test_geometry() {
+        let a = Vector::new([1, -2, 3]);
+        let b = Vector::new([-4, 0, 5]);
+        assert_eq!(a.manhattan_distance(b), 9_u32);
+        assert_eq!(a.chebyshev_distance(b), 5_u32);
+        assert_eq!(a.euclidean_squared(b), 33);
+        assert_eq!(a.dot(b), 11);
+        assert_eq!(a.signum(), Vector::new([1, -1, 1]));
+        assert_eq!(a.abs(), Vector::new([1, 2, 3]));
+        assert_eq!(a.min(b), Vector::new([-4, -2, 3]));
+        assert_eq!(a.max(b), Vector::new([1, 0, 5]));
+
+        let x = Vector::<3>::unit(0);
+        let y = Vector::<3>::unit(1);
+        assert_eq!(x.cross(y), Vector::unit(2));
+        assert_eq!(a.cross(b).dot(a), 0);
+        assert_eq!((a.x(), a.y(), a.z()), (1, -2, 3));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let point = Vector::new([0, 0]);
+        assert_eq!(
+            point.neighbours().collect::<Vec<_>>(),
+            [[-1, 0], [0, -1], [0, 1], [1, 0]].map(Vector::new)
+        );
+        assert_eq!(point.neighbours_diagonals().count(), 8);
+        assert_eq!(Vector::new([0_i64; 4]).neighbours_diagonals().count(), 80);
+        assert_eq!(
+            Vector::new([5]).neighbours_within(2).collect::<Vec<_>>(),
+            [3, 4, 6, 7].map(|x| Vector::new([x]))
+        );
+        assert_eq!(point.neighbours_within(2).count(), 24);
+
+        const STEPS: [Vector<3>; 2] = [Vector::new([1, 0, 0]), Vector::new([0, 0, -1])];
+        assert_eq!(
+            Neighbourhood::Offsets(&STEPS)
+                .of(Vector::new([1_i64, 2, 3]))
+                .collect::<Vec<_>>(),
+            [[2, 2, 3], [1, 2, 2]].map(Vector::new)
+        );
+        assert_eq!(Neighbourhood::HEX.of(Vector::new([0_i8, 0])).count(), 6);
+    }
+
+    #[test]
+    fn test_scalars() {
+        let big = Vector::new([3_000_000_000_i64, -1]);
+        assert_eq!(big.manhattan_distance(Vector::ZERO), 3_000_000_001);
+        assert_eq!(big.cast::<i32>(), None);
+        assert_eq!(
+            Vector::new([1, 2]).cast::<i64>(),
+            Some(Vector::new([1_i64, 2]))
+        );
+
+        let max = Vector::new([i32::MAX, 0]);
+        assert_eq!(max.checked_add(Vector::unit(0)), None);
+        assert_eq!(
+            max.checked_sub(Vector::unit(0)),
+            Some(Vector::new([i32::MAX - 1, 0]))
+        );
+        assert_eq!(max.checked_mul(2), None);
+        assert_eq!(
+            max.overflowing_add(Vector::new([1, 1])),
+            (Vector::new([i32::MIN, 1]), true)
+        );
+        assert_eq!(
+            max.overflowing_sub(Vector::unit(1)),
+            (Vector::new([i32::MAX, -1]), false)
+        );
+
+        let (min, max) = (Vector::new([i32::MIN, 0]), Vector::new([i32::MAX, 0]));
+        assert_eq!(min.manhattan_distance(max), u32::MAX);
+        assert_eq!(min.chebyshev_distance(max), u32::MAX);
+
+        let half = Rational64::new(1, 2);
+        let v = Vector::new([half, Rational64::from(3)]);
+        assert_eq!(
+            v * Rational64::from(2),
+            Vector::new([1, 6].map(Rational64::from))
+        );
+        assert_eq!(v.manhattan_distance(Vector::ZERO), Rational64::new(7, 2));
+
+        let f = Vector::new([0.5, -1.5]);
+        assert_eq!(f.abs().dot(Vector::new([2.0, 2.0])), 4.0);
     }
 }